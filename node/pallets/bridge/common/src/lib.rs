@@ -16,6 +16,7 @@ use codec::{Decode, Encode, EncodeLike};
 use frame_support::{
     Parameter, decl_error, decl_event, decl_module, decl_storage,
     dispatch::DispatchResult, ensure,
+    storage::IterableStorageMap,
     traits::{EnsureOrigin, Get},
     weights::{GetDispatchInfo, Pays},
 };
@@ -125,6 +126,10 @@ pub trait Trait: system::Trait + brelayers::Trait {
     type ChainIdentity: Get<ChainId>;
 
     type ProposalLifetime: Get<Self::BlockNumber>;
+    /// Max fees that can be set for a chain
+    type MaxChainFee: Get<Balance>;
+    /// True while proposals can't be executed, votes are rejected then so relayers can vote again later
+    type ProposalsPaused: Get<bool>;
 }
 
 decl_event! {
@@ -200,6 +205,10 @@ decl_error! {
         InvalidEthereumAddress,
         /// invalid fee recipient account
         InvalidFeesRecipientAccount,
+        /// fees account must differ from the bridge account
+        InvalidFeesAccount,
+        /// chain fees above MaxChainFee
+        ChainFeesTooHigh,
         /// rsymbol not mapped
         RsymbolNotMapped,
     }
@@ -259,6 +268,7 @@ decl_module! {
         const ChainIdentity: ChainId = T::ChainIdentity::get();
         const BridgeAccountId: T::AccountId = MODULE_ID.into_account();
         const ProposalLifetime: T::BlockNumber = T::ProposalLifetime::get();
+        const MaxChainFee: Balance = T::MaxChainFee::get();
 
         fn deposit_event() = default;
 
@@ -401,6 +411,7 @@ decl_module! {
             ensure!(brelayers::Module::<T>::is_relayer(src_id, &who), brelayers::Error::<T>::MustBeRelayer);
             ensure!(Self::chain_whitelisted(src_id), Error::<T>::ChainNotWhitelisted);
             ensure!(Self::resources(resource_id).is_some(), Error::<T>::ResourceDoesNotExist);
+            ensure!(!T::ProposalsPaused::get(), Error::<T>::ServicePaused);
 
             Self::commit_vote(who, nonce, src_id, call.clone())?;
             Self::try_resolve_proposal(nonce, src_id, call)
@@ -469,6 +480,7 @@ decl_module! {
 
             ensure!(Self::chain_whitelisted(id), Error::<T>::InvalidChainId);
             ensure!(<ProxyAccounts<T>>::contains_key(&who), Error::<T>::InvalidProxyAccount);
+            ensure!(fees <= T::MaxChainFee::get(), Error::<T>::ChainFeesTooHigh);
 
             <ChainFees>::insert(id, fees);
 
@@ -484,6 +496,7 @@ decl_module! {
         #[weight = 100_000_000]
         pub fn set_fees_recipient_account(origin, account: T::AccountId) -> DispatchResult {
             Self::ensure_admin(origin)?;
+            ensure!(account != Self::account_id(), Error::<T>::InvalidFeesAccount);
 
             <FeesRecipientAccount<T>>::put(account);
 
@@ -508,17 +521,26 @@ decl_module! {
 
 impl<T: Trait> Module<T> {
     pub fn swapable(recipient: &Vec<u8>, dest_id: ChainId) -> Result<(Balance, T::AccountId, T::AccountId), DispatchError> {
+        let swapable = Self::chain_swapable(dest_id)?;
+
+        if dest_id == ETH_CHAIN_ID || dest_id == BSC_CHAIN_ID {
+            Self::check_eth_recipient(recipient)?;
+        }
+
+        Ok(swapable)
+    }
+
+    /// Checks of swapable that do not depend on the recipient.
+    pub fn chain_swapable(dest_id: ChainId) -> Result<(Balance, T::AccountId, T::AccountId), DispatchError> {
         ensure!(!Self::check_is_paused(), Error::<T>::ServicePaused);
         ensure!(Self::chain_whitelisted(dest_id), Error::<T>::InvalidChainId);
 
         let fee = Self::get_chain_fees(dest_id).ok_or(Error::<T>::InvalidChainFee)?;
         let receiver = Self::get_fees_recipient_account().ok_or(Error::<T>::InvalidFeesRecipientAccount)?;
+        let bridger = Self::account_id();
+        ensure!(receiver != bridger, Error::<T>::InvalidFeesAccount);
 
-        if dest_id == ETH_CHAIN_ID || dest_id == BSC_CHAIN_ID {
-            Self::check_eth_recipient(recipient)?;
-        }
-
-        Ok((fee, receiver, Self::account_id()))
+        Ok((fee, receiver, bridger))
     }
 
     pub fn check_eth_recipient(recipient: &Vec<u8>) -> DispatchResult {
@@ -545,6 +567,15 @@ impl<T: Trait> Module<T> {
         return Self::chains(id) != None;
     }
 
+    /// All whitelisted chains, in ascending order
+    pub fn whitelisted_chains() -> Vec<ChainId> {
+        let mut chains: Vec<ChainId> = <ChainNonces as IterableStorageMap<ChainId, DepositNonce>>::iter()
+            .map(|(id, _)| id)
+            .collect();
+        chains.sort();
+        chains
+    }
+
     /// Get chain fees
     pub fn get_chain_fees(id: ChainId) -> Option<Balance> {
         return Self::chain_fees(id);
@@ -86,6 +86,7 @@ impl bridge_relayers::Trait for Test {
 parameter_types! {
 	pub const ChainIdentity: ChainId = 1;
 	pub const ProposalLifetime: BlockNumber = 50;
+	pub const MaxChainFee: u128 = 1_000;
 }
 
 impl Trait for Test {
@@ -94,6 +95,8 @@ impl Trait for Test {
 	type ChainIdentity = ChainIdentity;
 	type Proposal = Call;
 	type ProposalLifetime = ProposalLifetime;
+	type MaxChainFee = MaxChainFee;
+	type ProposalsPaused = ();
 }
 
 pub fn new_test_ext() -> sp_io::TestExternalities {
@@ -93,6 +93,19 @@ fn remove_whitelist_chain_should_work() {
 	});
 }
 
+#[test]
+fn whitelisted_chains_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(BridgeCommon::whitelisted_chains(), vec![]);
+		assert_ok!(BridgeCommon::whitelist_chain(Origin::root(), 3));
+		assert_ok!(BridgeCommon::whitelist_chain(Origin::root(), 2));
+		assert_eq!(BridgeCommon::whitelisted_chains(), vec![2, 3]);
+
+		assert_ok!(BridgeCommon::remove_whitelist_chain(Origin::root(), 2));
+		assert_eq!(BridgeCommon::whitelisted_chains(), vec![3]);
+	});
+}
+
 
 #[test]
 fn set_get_threshold() {
@@ -247,6 +260,11 @@ fn set_chain_fees_should_work() {
 		);
 		assert_ok!(BridgeCommon::set_proxy_accounts(Origin::root(), 42));
 		assert_eq!(BridgeCommon::chain_fees(2), None);
+		assert_noop!(
+			BridgeCommon::set_chain_fees(Origin::signed(42), 2, MaxChainFee::get() + 1),
+			Error::<Test>::ChainFeesTooHigh,
+		);
+		assert_ok!(BridgeCommon::set_chain_fees(Origin::signed(42), 2, MaxChainFee::get()));
 		assert_ok!(BridgeCommon::set_chain_fees(Origin::signed(42), 2, 10));
 		assert_eq!(BridgeCommon::chain_fees(2), Some(10));
 	});
@@ -260,6 +278,10 @@ fn set_fees_recipient_account_should_work() {
 			sp_runtime::traits::BadOrigin,
 		);
 		assert_eq!(BridgeCommon::fees_recipient_account(), None);
+		assert_noop!(
+			BridgeCommon::set_fees_recipient_account(Origin::root(), BridgeCommon::account_id()),
+			Error::<Test>::InvalidFeesAccount,
+		);
 		assert_ok!(BridgeCommon::set_fees_recipient_account(Origin::root(), 1));
 		assert_eq!(BridgeCommon::fees_recipient_account(), Some(1));
 	});
@@ -0,0 +1,37 @@
+// Copyright 2019-2021 Stafi Protocol.
+// This file is part of Stafi.
+
+// Stafi is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Stafi.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Runtime API definition for the bridge swap pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use node_primitives::{AccountId, Balance, ChainId};
+use sp_core::U256;
+use sp_runtime::DispatchError;
+use sp_std::prelude::*;
+
+sp_api::decl_runtime_apis! {
+    /// Lets wallets preflight bridge transfers.
+    pub trait BridgeSwapApi {
+        /// Flat fee of bridge common for transfers to dest_id, None if it is not set.
+        fn chain_transfer_fee(dest_id: ChainId) -> Option<Balance>;
+        /// True if dest_id is whitelisted by bridge common.
+        fn is_chain_whitelisted(dest_id: ChainId) -> bool;
+        /// All chains whitelisted by bridge common.
+        fn whitelisted_chains() -> Vec<ChainId>;
+        /// Free native balance of the bridge account, the liquidity available to inbound transfers.
+        fn bridge_account_balance() -> Balance;
+        /// Native amount bridged out to dest_id over the chain's lifetime.
+        fn total_bridged_out(dest_id: ChainId) -> U256;
+        /// (fee, swap amount) of a native transfer of amount from who to dest_id, or the error it would fail with.
+        fn estimate_transfer_native(who: AccountId, amount: Balance, dest_id: ChainId) -> Result<(Balance, Balance), DispatchError>;
+    }
+}
@@ -11,26 +11,57 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
-use sp_std::prelude::*;
+use sp_std::{prelude::*, convert::TryInto, marker::PhantomData};
 use bridge_common::{self as bridge, ResourceId};
 use frame_support::{
-    decl_error, decl_module, dispatch::DispatchResult, ensure,
+    decl_error, decl_event, decl_module, decl_storage, dispatch::DispatchResult, ensure, transactional,
+    weights::Weight,
     traits::{
         Currency, EnsureOrigin, Get,
-        ExistenceRequirement::{KeepAlive},
+        ExistenceRequirement::{AllowDeath, KeepAlive},
     },
 };
 use frame_system::{self as system, ensure_signed};
-use sp_runtime::{traits::{Zero, Saturating}};
+use sp_runtime::{DispatchError, traits::{Zero, Saturating, CheckedAdd}};
 use sp_core::U256;
 use sp_arithmetic::traits::SaturatedConversion;
-use node_primitives::{ChainId, RSymbol, XSymbol};
+use node_primitives::{ChainId, RSymbol, XSymbol, Balance};
 use rtoken_balances::{traits::{Currency as RCurrency}};
 use xtoken_balances::{traits::{Currency as XCurrency}};
 
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+pub mod models;
+pub use models::*;
+
+/// basis points denominator of percent fees
+pub const FEE_BPS_DENOMINATOR: u128 = 10_000;
+/// most accounts fees can be split among
+pub const MAX_FEE_RECIPIENTS: u32 = 10;
+
+/// ProposalsPaused of bridge common, relayers can't vote on proposals while swaps are paused.
+pub struct IsSwapPaused<T>(PhantomData<T>);
+
+impl<T: Trait> Get<bool> for IsSwapPaused<T> {
+    fn get() -> bool {
+        <Module<T>>::swap_paused()
+    }
+}
+
+/// Weight of a native transfer, the storage of bridge common and this pallet it reads and writes
+/// and a balance transfer for each fee recipient.
+fn native_transfer_weight<T: Trait>() -> Weight {
+    let recipients = MAX_FEE_RECIPIENTS as Weight;
+    T::DbWeight::get().reads_writes(20 + recipients, 5 + recipients).saturating_add(195_000_000)
+}
+
 type BalanceOf<T> = <<T as Trait>::Currency as Currency<<T as frame_system::Trait>::AccountId>>::Balance;
 
 pub trait Trait: system::Trait + bridge::Trait {
+    type Event: From<Event<Self>> + Into<<Self as system::Trait>::Event>;
     /// The currency mechanism.
     type Currency: Currency<Self::AccountId>;
     /// Currency mechanism of rtoken
@@ -44,6 +75,23 @@ pub trait Trait: system::Trait + bridge::Trait {
 
     // Ids can be defined by the runtime and passed in, perhaps from blake2b_128 hashes.
     type NativeTokenId: Get<ResourceId>;
+
+    /// Max byte length of the memo of a native transfer
+    type MaxMemoLen: Get<u32>;
+}
+
+decl_event! {
+    pub enum Event<T> where
+        AccountId = <T as system::Trait>::AccountId,
+        Balance = BalanceOf<T>
+    {
+        /// NativeTransfer: (source, dest_id, recipient, amount, fees, deposit nonce)
+        NativeTransfer(AccountId, ChainId, Vec<u8>, Balance, Balance, u64),
+        /// NativeTransferWithMemo: (source, dest_id, recipient, amount, fees, deposit nonce, memo)
+        NativeTransferWithMemo(AccountId, ChainId, Vec<u8>, Balance, Balance, u64, Vec<u8>),
+        /// swap paused or unpaused
+        SwapPausedChanged(bool),
+    }
 }
 
 decl_error! {
@@ -53,6 +101,68 @@ decl_error! {
         RsymbolNotMapped,
         XsymbolNotMapped,
         ResourceNotMapped,
+        InvalidTransferLimits,
+        AmountBelowMin,
+        AmountAboveMax,
+        UnknownResource,
+        InvalidFeeMode,
+        BridgePaused,
+        InvalidVolumeWindow,
+        VolumeLimitExceeded,
+        InvalidRecipientLen,
+        InvalidRecipient,
+        ParamsErr,
+        InvalidFeeRecipients,
+        AmountZero,
+        AmountTooLarge,
+        InvalidResourceId,
+        FeeExceedsMax,
+        SwapAmountTooSmall,
+        TooManyFeeRecipients,
+        ResourceAlreadyMapped,
+    }
+}
+
+decl_storage! {
+    trait Store for Module<T: Trait> as BridgeSwap {
+        /// (min, max) amount allowed for native transfers to a chain
+        pub ChainTransferLimits get(fn chain_transfer_limits): map hasher(twox_64_concat) ChainId => Option<(BalanceOf<T>, BalanceOf<T>)>;
+
+        /// how native transfer fees are computed for a chain
+        pub ChainFeeMode get(fn chain_fee_mode): map hasher(twox_64_concat) ChainId => FeeMode;
+        /// how percent fees are rounded
+        pub FeeRounding get(fn fee_rounding): FeeRoundingMode;
+        /// lowest fee a native transfer is charged whatever the fee mode, zero means no floor
+        pub MinAbsoluteFee get(fn min_absolute_fee): BalanceOf<T>;
+        /// lowest amount a native transfer can bridge, zero means no minimum
+        pub MinSwapAmount get(fn min_swap_amount): BalanceOf<T>;
+
+        /// True if swaps in and out of the bridge are paused
+        pub SwapPaused get(fn swap_paused): bool = false;
+
+        /// max native amount an account can transfer out per window, zero means no limit
+        pub DailyVolumeLimit get(fn daily_volume_limit): BalanceOf<T>;
+        /// length in blocks of the volume window
+        pub DailyWindowBlocks get(fn daily_window_blocks): T::BlockNumber;
+        /// (window start block, amount transferred in window) of an account
+        pub AccountVolume get(fn account_volume): map hasher(blake2_128_concat) T::AccountId => Option<(T::BlockNumber, BalanceOf<T>)>;
+
+        /// (min, max) byte length of recipients on a chain
+        pub ChainRecipientLen get(fn chain_recipient_len): map hasher(twox_64_concat) ChainId => Option<(u32, u32)>;
+
+        /// (account, basis points) that fees are split among, the first one takes the remainder
+        pub FeeRecipients get(fn fee_recipients): Vec<(T::AccountId, u32)>;
+
+        /// accounts that pay no fee on native transfers
+        pub FeeExempt get(fn fee_exempt): map hasher(blake2_128_concat) T::AccountId => bool;
+
+        /// rId of the native token on a chain, NativeTokenId if not set
+        pub ChainNativeResource get(fn chain_native_resource): map hasher(twox_64_concat) ChainId => Option<ResourceId>;
+        /// rIds set in ChainNativeResource, each for one chain
+        pub NativeResources get(fn native_resources): map hasher(blake2_128_concat) ResourceId => bool;
+
+        /// native amount bridged out to a chain over its lifetime
+        pub TotalBridgedOut get(fn total_bridged_out): map hasher(twox_64_concat) ChainId => U256;
     }
 }
 
@@ -61,31 +171,24 @@ decl_module! {
         type Error = Error<T>;
 
         const NativeTokenId: ResourceId = T::NativeTokenId::get();
+        const MaxMemoLen: u32 = T::MaxMemoLen::get();
+
+        fn deposit_event() = default;
 
         /// Transfers some amount of the native token to some recipient on a (whitelisted) destination chain.
-        #[weight = 195_000_000]
+        /// amount plus fee must fit in a u128, the width bridged amounts are encoded from.
+        #[weight = native_transfer_weight::<T>()]
         pub fn transfer_native(origin, amount: BalanceOf<T>, recipient: Vec<u8>, dest_id: ChainId) -> DispatchResult {
             let source = ensure_signed(origin)?;
-
-            let (fee, receiver, bridger) = <bridge::Module<T>>::swapable(&recipient, dest_id)?;
-            let fee: BalanceOf<T> = fee.saturated_into();
-
-            let total_amount = amount.saturating_add(fee);
-            T::Currency::transfer(&source, &bridger, total_amount, KeepAlive)?;
-
-            if fee > Zero::zero() {
-                T::Currency::transfer(&bridger, &receiver, fee, KeepAlive)?;
-            }
-
-            let resource_id = T::NativeTokenId::get();
-            <bridge::Module<T>>::transfer_fungible(source, dest_id, resource_id, recipient, U256::from(amount.saturated_into::<u128>()))
+            Self::do_transfer_native(source, amount, recipient, dest_id, None, None)
         }
 
         /// Allows the bridge to swap native token back
         #[weight = 195_000_000]
-        pub fn transfer_native_back(origin, recipient: T::AccountId, amount: BalanceOf<T>, _resource_id: ResourceId) -> DispatchResult {
+        pub fn transfer_native_back(origin, recipient: T::AccountId, amount: BalanceOf<T>, resource_id: ResourceId) -> DispatchResult {
             let bridge_id = T::BridgeOrigin::ensure_origin(origin)?;
-            T::Currency::transfer(&bridge_id, &recipient, amount, KeepAlive)?;
+            ensure!(Self::is_native_resource(&resource_id), Error::<T>::ResourceNotMapped);
+            T::Currency::transfer(&bridge_id, &recipient, amount, AllowDeath)?;
 
             Ok(())
         }
@@ -94,24 +197,8 @@ decl_module! {
         #[weight = 195_000_000]
         pub fn transfer_rtoken(origin, symbol: RSymbol, amount: u128, recipient: Vec<u8>, dest_id: ChainId) -> DispatchResult {
             let who = ensure_signed(origin)?;
-
-            let (fee, receiver, bridger) = <bridge::Module<T>>::swapable(&recipient, dest_id)?;
-            let resource = <bridge::Module<T>>::rsymbol_resource(&symbol).ok_or(Error::<T>::RsymbolNotMapped)?;
-            let new_rbalance = T::RCurrency::free_balance(&who, symbol).checked_sub(amount)
-                .ok_or(Error::<T>::InsufficientRbalance)?;
-            T::RCurrency::ensure_can_withdraw(&who, symbol, amount, new_rbalance)?;
-
-            if fee > 0 {
-                T::Currency::transfer(&who, &receiver, fee.saturated_into(), KeepAlive)?;
-            }
-
-            if symbol == RSymbol::RETH {
-                T::RCurrency::burn(&who, symbol, amount)?;
-            } else {
-                T::RCurrency::transfer(&who, &bridger, symbol, amount)?;
-            }
-
-            <bridge::Module<T>>::transfer_fungible(who, dest_id, resource, recipient, U256::from(amount))
+            let resource = <bridge::Module<T>>::rsymbol_resource(&symbol);
+            Self::do_transfer_rtoken(who, symbol, amount, recipient, dest_id, resource)
         }
 
         /// Allows the bridge to swap rtoken back
@@ -133,19 +220,8 @@ decl_module! {
         #[weight = 195_000_000]
         pub fn transfer_xtoken(origin, symbol: XSymbol, amount: u128, recipient: Vec<u8>, dest_id: ChainId) -> DispatchResult {
             let who = ensure_signed(origin)?;
-
-            let (fee, receiver, _) = <bridge::Module<T>>::swapable(&recipient, dest_id)?;
-            let resource = <bridge::Module<T>>::xsymbol_resource(&symbol).ok_or(Error::<T>::XsymbolNotMapped)?;
-            let new_rbalance = T::XCurrency::free_balance(&who, symbol).checked_sub(amount)
-                .ok_or(Error::<T>::InsufficientXbalance)?;
-            T::XCurrency::ensure_can_withdraw(&who, symbol, amount, new_rbalance)?;
-
-            if fee > 0 {
-                T::Currency::transfer(&who, &receiver, fee.saturated_into(), KeepAlive)?;
-            }
-            T::XCurrency::burn(&who, symbol, amount)?;
-
-            <bridge::Module<T>>::transfer_fungible(who, dest_id, resource, recipient, U256::from(amount))
+            let resource = <bridge::Module<T>>::xsymbol_resource(&symbol);
+            Self::do_transfer_xtoken(who, symbol, amount, recipient, dest_id, resource)
         }
 
         /// Allows the bridge to swap xtoken back
@@ -158,6 +234,437 @@ decl_module! {
             T::XCurrency::mint(&recipient, sym, amount)?;
             Ok(())
         }
+
+        /// Transfers some amount of the native token to some recipient on a (whitelisted) destination chain with a memo.
+        #[weight = native_transfer_weight::<T>()]
+        pub fn transfer_native_with_memo(origin, amount: BalanceOf<T>, recipient: Vec<u8>, dest_id: ChainId, memo: Vec<u8>) -> DispatchResult {
+            let source = ensure_signed(origin)?;
+            ensure!(memo.len() as u32 <= T::MaxMemoLen::get(), Error::<T>::ParamsErr);
+            Self::do_transfer_native(source, amount, recipient, dest_id, Some(memo), None)
+        }
+
+        /// Transfers some amount of the native token to some recipient on a (whitelisted) destination chain,
+        /// failing if the fee charged would exceed max_fee.
+        #[weight = native_transfer_weight::<T>()]
+        pub fn transfer_native_with_max_fee(origin, amount: BalanceOf<T>, recipient: Vec<u8>, dest_id: ChainId, max_fee: BalanceOf<T>) -> DispatchResult {
+            let source = ensure_signed(origin)?;
+            Self::do_transfer_native(source, amount, recipient, dest_id, None, Some(max_fee))
+        }
+
+        /// Transfers some amount of the token mapped to resource_id in bridge common to some recipient on a (whitelisted) destination chain.
+        /// resource_id is the native token if it is the native rId of dest_id.
+        #[weight = native_transfer_weight::<T>()]
+        pub fn transfer_token(origin, amount: u128, recipient: Vec<u8>, dest_id: ChainId, resource_id: ResourceId) -> DispatchResult {
+            let source = ensure_signed(origin)?;
+
+            if resource_id == Self::native_resource(dest_id) {
+                let amount: BalanceOf<T> = amount.try_into().map_err(|_| Error::<T>::AmountTooLarge)?;
+                return Self::do_transfer_native(source, amount, recipient, dest_id, None, None);
+            }
+            if let Some(symbol) = <bridge::Module<T>>::resource_rsymbol(&resource_id) {
+                return Self::do_transfer_rtoken(source, symbol, amount, recipient, dest_id, Some(resource_id));
+            }
+            let symbol = <bridge::Module<T>>::resource_xsymbol(&resource_id).ok_or(Error::<T>::UnknownResource)?;
+            Self::do_transfer_xtoken(source, symbol, amount, recipient, dest_id, Some(resource_id))
+        }
+
+        /// Set the (min, max) amount of native token that can be transferred to a chain.
+        #[weight = 10_000]
+        pub fn set_chain_transfer_limits(origin, dest_id: ChainId, min: BalanceOf<T>, max: BalanceOf<T>) -> DispatchResult {
+            <bridge::Module<T>>::ensure_admin(origin)?;
+            ensure!(min <= max, Error::<T>::InvalidTransferLimits);
+            <ChainTransferLimits<T>>::insert(dest_id, (min, max));
+            Ok(())
+        }
+
+        /// Remove the transfer limits of a chain.
+        #[weight = 10_000]
+        pub fn remove_chain_transfer_limits(origin, dest_id: ChainId) -> DispatchResult {
+            <bridge::Module<T>>::ensure_admin(origin)?;
+            <ChainTransferLimits<T>>::remove(dest_id);
+            Ok(())
+        }
+
+        /// Set the fee mode of native transfers to a chain.
+        #[weight = 10_000]
+        pub fn set_chain_fee_mode(origin, dest_id: ChainId, mode: FeeMode) -> DispatchResult {
+            <bridge::Module<T>>::ensure_admin(origin)?;
+            if let FeeMode::Percent(bps) = mode {
+                ensure!(bps as u128 <= FEE_BPS_DENOMINATOR, Error::<T>::InvalidFeeMode);
+            }
+            <ChainFeeMode>::insert(dest_id, mode);
+            Ok(())
+        }
+
+        /// Set the lowest amount native transfers can bridge, zero disables the minimum.
+        #[weight = 10_000]
+        pub fn set_min_swap_amount(origin, amount: BalanceOf<T>) -> DispatchResult {
+            <bridge::Module<T>>::ensure_admin(origin)?;
+            <MinSwapAmount<T>>::put(amount);
+            Ok(())
+        }
+
+        /// Set how percent fees are rounded.
+        #[weight = 10_000]
+        pub fn set_fee_rounding_mode(origin, mode: FeeRoundingMode) -> DispatchResult {
+            <bridge::Module<T>>::ensure_admin(origin)?;
+            <FeeRounding>::put(mode);
+            Ok(())
+        }
+
+        /// Set the lowest fee of native transfers, zero disables the floor. It can't exceed MaxChainFee.
+        #[weight = 10_000]
+        pub fn set_min_absolute_fee(origin, fee: BalanceOf<T>) -> DispatchResult {
+            <bridge::Module<T>>::ensure_admin(origin)?;
+            ensure!(fee.saturated_into::<Balance>() <= T::MaxChainFee::get(), bridge::Error::<T>::ChainFeesTooHigh);
+            <MinAbsoluteFee<T>>::put(fee);
+            Ok(())
+        }
+
+        /// Pause or unpause all swaps.
+        #[weight = 10_000]
+        pub fn set_swap_paused(origin, paused: bool) -> DispatchResult {
+            <bridge::Module<T>>::ensure_admin(origin)?;
+            <SwapPaused>::put(paused);
+            Self::deposit_event(RawEvent::SwapPausedChanged(paused));
+            Ok(())
+        }
+
+        /// Set the per-account volume limit of native transfers and its window in blocks, zero limit disables it.
+        #[weight = 10_000]
+        pub fn set_daily_volume_limit(origin, limit: BalanceOf<T>, window_blocks: T::BlockNumber) -> DispatchResult {
+            <bridge::Module<T>>::ensure_admin(origin)?;
+            ensure!(limit.is_zero() || !window_blocks.is_zero(), Error::<T>::InvalidVolumeWindow);
+            <DailyVolumeLimit<T>>::put(limit);
+            <DailyWindowBlocks<T>>::put(window_blocks);
+            Ok(())
+        }
+
+        /// Set the (min, max) byte length of recipients on a chain.
+        #[weight = 10_000]
+        pub fn set_chain_recipient_len(origin, dest_id: ChainId, min: u32, max: u32) -> DispatchResult {
+            <bridge::Module<T>>::ensure_admin(origin)?;
+            ensure!(min <= max, Error::<T>::InvalidRecipientLen);
+            <ChainRecipientLen>::insert(dest_id, (min, max));
+            Ok(())
+        }
+
+        /// Remove the recipient length check of a chain.
+        #[weight = 10_000]
+        pub fn remove_chain_recipient_len(origin, dest_id: ChainId) -> DispatchResult {
+            <bridge::Module<T>>::ensure_admin(origin)?;
+            <ChainRecipientLen>::remove(dest_id);
+            Ok(())
+        }
+
+        /// Set the accounts fees are split among, their basis points must sum to 10_000.
+        /// An empty list pays all fees to the fees recipient account of bridge common.
+        #[weight = 10_000]
+        pub fn set_fee_recipients(origin, recipients: Vec<(T::AccountId, u32)>) -> DispatchResult {
+            <bridge::Module<T>>::ensure_admin(origin)?;
+            ensure!(recipients.len() as u32 <= MAX_FEE_RECIPIENTS, Error::<T>::TooManyFeeRecipients);
+            if !recipients.is_empty() {
+                let total = recipients.iter().fold(0u128, |acc, (_, bps)| acc.saturating_add(*bps as u128));
+                ensure!(total == FEE_BPS_DENOMINATOR, Error::<T>::InvalidFeeRecipients);
+                let bridger = <bridge::Module<T>>::account_id();
+                ensure!(recipients.iter().all(|(who, _)| *who != bridger), bridge::Error::<T>::InvalidFeesAccount);
+            }
+            <FeeRecipients<T>>::put(recipients);
+            Ok(())
+        }
+
+        /// Exempt who from native transfer fees.
+        #[weight = 10_000]
+        pub fn add_fee_exempt(origin, who: T::AccountId) -> DispatchResult {
+            <bridge::Module<T>>::ensure_admin(origin)?;
+            <FeeExempt<T>>::insert(&who, true);
+            Ok(())
+        }
+
+        /// Make who pay native transfer fees again.
+        #[weight = 10_000]
+        pub fn remove_fee_exempt(origin, who: T::AccountId) -> DispatchResult {
+            <bridge::Module<T>>::ensure_admin(origin)?;
+            <FeeExempt<T>>::remove(&who);
+            Ok(())
+        }
+
+        /// Set the rId native transfers to dest_id are bridged with.
+        #[weight = 10_000]
+        pub fn set_chain_native_resource(origin, dest_id: ChainId, resource_id: ResourceId) -> DispatchResult {
+            <bridge::Module<T>>::ensure_admin(origin)?;
+            ensure!(resource_id != [0; 32], Error::<T>::InvalidResourceId);
+            ensure!(
+                <bridge::Module<T>>::resource_rsymbol(&resource_id).is_none() && <bridge::Module<T>>::resource_xsymbol(&resource_id).is_none(),
+                Error::<T>::ResourceAlreadyMapped
+            );
+            let previous = Self::chain_native_resource(dest_id);
+            ensure!(previous == Some(resource_id) || !Self::native_resources(&resource_id), Error::<T>::ResourceAlreadyMapped);
+
+            if let Some(previous) = previous {
+                <NativeResources>::remove(&previous);
+            }
+            <ChainNativeResource>::insert(dest_id, resource_id);
+            <NativeResources>::insert(&resource_id, true);
+            Ok(())
+        }
+
+        /// Bridge native transfers to dest_id with NativeTokenId again.
+        #[weight = 10_000]
+        pub fn remove_chain_native_resource(origin, dest_id: ChainId) -> DispatchResult {
+            <bridge::Module<T>>::ensure_admin(origin)?;
+            if let Some(resource_id) = <ChainNativeResource>::take(dest_id) {
+                <NativeResources>::remove(&resource_id);
+            }
+            Ok(())
+        }
     }
 }
 
+impl<T: Trait> Module<T> {
+    /// Outbound transfers move currency before transfer_fungible, they are transactional
+    /// so that any failure on the way reverts the currency moves as well.
+    ///
+    /// Native transfers run every check first, then move amount and fee into the bridge account,
+    /// pay out the fee, and call transfer_fungible last. The volume window is only written once
+    /// transfer_fungible succeeded. An error at any step, transfer_fungible included, reverts
+    /// every currency move, so no funds are left in the bridge account without a matching
+    /// FungibleTransfer.
+    #[transactional]
+    fn do_transfer_native(
+        source: T::AccountId,
+        amount: BalanceOf<T>,
+        recipient: Vec<u8>,
+        dest_id: ChainId,
+        memo: Option<Vec<u8>>,
+        max_fee: Option<BalanceOf<T>>,
+    ) -> DispatchResult {
+        Self::ensure_swap_not_paused()?;
+        let (chain_fee, receiver, bridger) = <bridge::Module<T>>::swapable(&recipient, dest_id)?;
+        Self::check_recipient(dest_id, &recipient)?;
+        let (fee, raw_amount, volume) = Self::native_transfer_amounts(&source, amount, dest_id, chain_fee)?;
+        if let Some(max_fee) = max_fee {
+            ensure!(fee <= max_fee, Error::<T>::FeeExceedsMax);
+        }
+
+        T::Currency::transfer(&source, &bridger, amount.saturating_add(fee), KeepAlive)?;
+
+        Self::pay_fee(&bridger, &receiver, fee)?;
+
+        let resource_id = Self::native_resource(dest_id);
+        <bridge::Module<T>>::transfer_fungible(source.clone(), dest_id, resource_id, recipient.clone(), U256::from(raw_amount))?;
+        let nonce = <bridge::Module<T>>::chains(dest_id).unwrap_or_default();
+
+        if let Some(volume) = volume {
+            <AccountVolume<T>>::insert(&source, volume);
+        }
+        <TotalBridgedOut>::mutate(dest_id, |total| *total = total.saturating_add(U256::from(raw_amount)));
+        match memo {
+            Some(memo) => Self::deposit_event(RawEvent::NativeTransferWithMemo(source, dest_id, recipient, amount, fee, nonce, memo)),
+            None => Self::deposit_event(RawEvent::NativeTransfer(source, dest_id, recipient, amount, fee, nonce)),
+        }
+        Ok(())
+    }
+
+    #[transactional]
+    fn do_transfer_rtoken(who: T::AccountId, symbol: RSymbol, amount: u128, recipient: Vec<u8>, dest_id: ChainId, resource: Option<ResourceId>) -> DispatchResult {
+        Self::ensure_swap_not_paused()?;
+        let (fee, receiver, bridger) = <bridge::Module<T>>::swapable(&recipient, dest_id)?;
+        Self::check_recipient(dest_id, &recipient)?;
+        let resource = resource.ok_or(Error::<T>::RsymbolNotMapped)?;
+        let new_rbalance = T::RCurrency::free_balance(&who, symbol).checked_sub(amount)
+            .ok_or(Error::<T>::InsufficientRbalance)?;
+        T::RCurrency::ensure_can_withdraw(&who, symbol, amount, new_rbalance)?;
+
+        Self::pay_fee(&who, &receiver, fee.saturated_into())?;
+
+        if symbol == RSymbol::RETH {
+            T::RCurrency::burn(&who, symbol, amount)?;
+        } else {
+            T::RCurrency::transfer(&who, &bridger, symbol, amount)?;
+        }
+
+        <bridge::Module<T>>::transfer_fungible(who, dest_id, resource, recipient, U256::from(amount))
+    }
+
+    #[transactional]
+    fn do_transfer_xtoken(who: T::AccountId, symbol: XSymbol, amount: u128, recipient: Vec<u8>, dest_id: ChainId, resource: Option<ResourceId>) -> DispatchResult {
+        Self::ensure_swap_not_paused()?;
+        let (fee, receiver, _) = <bridge::Module<T>>::swapable(&recipient, dest_id)?;
+        Self::check_recipient(dest_id, &recipient)?;
+        let resource = resource.ok_or(Error::<T>::XsymbolNotMapped)?;
+        let new_rbalance = T::XCurrency::free_balance(&who, symbol).checked_sub(amount)
+            .ok_or(Error::<T>::InsufficientXbalance)?;
+        T::XCurrency::ensure_can_withdraw(&who, symbol, amount, new_rbalance)?;
+
+        Self::pay_fee(&who, &receiver, fee.saturated_into())?;
+        T::XCurrency::burn(&who, symbol, amount)?;
+
+        <bridge::Module<T>>::transfer_fungible(who, dest_id, resource, recipient, U256::from(amount))
+    }
+
+    /// (fee, raw amount, volume window after) of a native transfer, shared by transfers and estimates.
+    fn native_transfer_amounts(
+        source: &T::AccountId,
+        amount: BalanceOf<T>,
+        dest_id: ChainId,
+        chain_fee: Balance,
+    ) -> Result<(BalanceOf<T>, u128, Option<(T::BlockNumber, BalanceOf<T>)>), DispatchError> {
+        let (fee, amount) = Self::compute_fees_and_amount(source, amount, dest_id, chain_fee)?;
+        Self::check_transfer_limits(dest_id, amount)?;
+        let volume = Self::volume_after(source, amount)?;
+
+        let raw_amount: u128 = amount.try_into().map_err(|_| Error::<T>::AmountTooLarge)?;
+
+        Ok((fee, raw_amount, volume))
+    }
+
+    /// (fees, swap amount) of a native transfer of amount from source to dest_id.
+    /// The caller must have run chain_swapable already, which checks the whitelist and returns chain_fee.
+    /// Fees are charged on top, so the swap amount is always amount and amount + fees must not overflow.
+    fn compute_fees_and_amount(
+        source: &T::AccountId,
+        amount: BalanceOf<T>,
+        dest_id: ChainId,
+        chain_fee: Balance,
+    ) -> Result<(BalanceOf<T>, BalanceOf<T>), DispatchError> {
+        ensure!(!amount.is_zero(), Error::<T>::AmountZero);
+        ensure!(amount >= Self::min_swap_amount(), Error::<T>::SwapAmountTooSmall);
+
+        let fee = if Self::fee_exempt(source) { Zero::zero() } else { Self::native_fee(dest_id, amount, chain_fee) };
+        amount.checked_add(&fee).ok_or(Error::<T>::AmountTooLarge)?;
+
+        Ok((fee, amount))
+    }
+
+    /// (fee, swap amount) that a native transfer of amount from source to dest_id would take and bridge,
+    /// or the error it would fail with. The recipient and the balance of source are not checked.
+    pub fn estimate_transfer_native(source: &T::AccountId, amount: BalanceOf<T>, dest_id: ChainId) -> Result<(BalanceOf<T>, BalanceOf<T>), DispatchError> {
+        Self::ensure_swap_not_paused()?;
+        let (chain_fee, _, _) = <bridge::Module<T>>::chain_swapable(dest_id)?;
+        let (fee, _, _) = Self::native_transfer_amounts(source, amount, dest_id, chain_fee)?;
+        Ok((fee, amount))
+    }
+
+    /// Flat fee of bridge common for transfers to dest_id, used by the runtime api.
+    pub fn chain_transfer_fee(dest_id: ChainId) -> Option<Balance> {
+        <bridge::Module<T>>::get_chain_fees(dest_id)
+    }
+
+    /// True if dest_id is whitelisted, used by the runtime api.
+    pub fn is_chain_whitelisted(dest_id: ChainId) -> bool {
+        <bridge::Module<T>>::chain_whitelisted(dest_id)
+    }
+
+    /// Free native balance of the bridge account, used by the runtime api.
+    pub fn bridge_account_balance() -> BalanceOf<T> {
+        T::Currency::free_balance(&<bridge::Module<T>>::account_id())
+    }
+
+    /// Chains that can be transferred to, used by the runtime api.
+    pub fn whitelisted_chains() -> Vec<ChainId> {
+        <bridge::Module<T>>::whitelisted_chains()
+    }
+
+    /// rId of the native token on dest_id.
+    pub fn native_resource(dest_id: ChainId) -> ResourceId {
+        Self::chain_native_resource(dest_id).unwrap_or_else(T::NativeTokenId::get)
+    }
+
+    /// Whether resource_id is NativeTokenId or the native rId of some chain.
+    pub fn is_native_resource(resource_id: &ResourceId) -> bool {
+        *resource_id == T::NativeTokenId::get() || Self::native_resources(resource_id)
+    }
+
+    /// Errors with BridgePaused if swaps are paused.
+    pub fn ensure_swap_not_paused() -> DispatchResult {
+        ensure!(!Self::swap_paused(), Error::<T>::BridgePaused);
+        Ok(())
+    }
+
+    /// Pays fee from the from account to the fee recipients, or to receiver if no split is set.
+    fn pay_fee(from: &T::AccountId, receiver: &T::AccountId, fee: BalanceOf<T>) -> DispatchResult {
+        if fee.is_zero() {
+            return Ok(());
+        }
+
+        let recipients = Self::fee_recipients();
+        if recipients.is_empty() {
+            return T::Currency::transfer(from, receiver, fee, KeepAlive);
+        }
+
+        let total = fee.saturated_into::<u128>();
+        let mut shares: Vec<u128> = recipients.iter()
+            .map(|(_, bps)| total.saturating_mul(*bps as u128) / FEE_BPS_DENOMINATOR)
+            .collect();
+        let paid = shares.iter().fold(0u128, |acc, share| acc.saturating_add(*share));
+        shares[0] = shares[0].saturating_add(total.saturating_sub(paid));
+
+        for ((who, _), share) in recipients.iter().zip(shares) {
+            if share > 0 {
+                T::Currency::transfer(from, who, share.saturated_into(), KeepAlive)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Fee of a native transfer of amount to dest_id, chain_fee is the flat fee of bridge common.
+    /// Never below MinAbsoluteFee.
+    pub fn native_fee(dest_id: ChainId, amount: BalanceOf<T>, chain_fee: Balance) -> BalanceOf<T> {
+        let fee: BalanceOf<T> = match Self::chain_fee_mode(dest_id) {
+            FeeMode::Flat => chain_fee.saturated_into(),
+            FeeMode::Percent(bps) => {
+                // computed in U256 so that amount * bps never overflows, bps is at most
+                // FEE_BPS_DENOMINATOR so the fee always fits back in a u128
+                let product = U256::from(amount.saturated_into::<u128>()) * U256::from(bps);
+                let denominator = U256::from(FEE_BPS_DENOMINATOR);
+                let fee = match Self::fee_rounding() {
+                    FeeRoundingMode::Down => product / denominator,
+                    FeeRoundingMode::Up => (product + denominator - 1) / denominator,
+                    FeeRoundingMode::Nearest => (product + denominator / 2) / denominator,
+                };
+                fee.low_u128().saturated_into()
+            },
+        };
+        fee.max(Self::min_absolute_fee())
+    }
+
+    /// Volume window of who after transferring amount, None if there is no volume limit.
+    fn volume_after(who: &T::AccountId, amount: BalanceOf<T>) -> Result<Option<(T::BlockNumber, BalanceOf<T>)>, DispatchError> {
+        let limit = Self::daily_volume_limit();
+        if limit.is_zero() {
+            return Ok(None);
+        }
+
+        let now = system::Module::<T>::block_number();
+        let (start, volume) = match Self::account_volume(who) {
+            Some((start, volume)) if now < start.saturating_add(Self::daily_window_blocks()) => (start, volume),
+            _ => (now, Zero::zero()),
+        };
+
+        let volume = volume.saturating_add(amount);
+        ensure!(volume <= limit, Error::<T>::VolumeLimitExceeded);
+        Ok(Some((start, volume)))
+    }
+
+    /// Checks recipient length against the configured range of dest_id, chains without a range are unchecked.
+    pub fn check_recipient(dest_id: ChainId, recipient: &Vec<u8>) -> DispatchResult {
+        if let Some((min, max)) = Self::chain_recipient_len(dest_id) {
+            let len = recipient.len() as u32;
+            ensure!(len >= min && len <= max, Error::<T>::InvalidRecipient);
+        }
+
+        Ok(())
+    }
+
+    /// Checks amount against the transfer limits of dest_id, chains without limits are unchecked.
+    pub fn check_transfer_limits(dest_id: ChainId, amount: BalanceOf<T>) -> DispatchResult {
+        if let Some((min, max)) = Self::chain_transfer_limits(dest_id) {
+            ensure!(amount >= min, Error::<T>::AmountBelowMin);
+            ensure!(amount <= max, Error::<T>::AmountAboveMax);
+        }
+
+        Ok(())
+    }
+}
@@ -13,9 +13,10 @@ use sp_std::{cell::RefCell};
 use sp_io::hashing::blake2_128;
 use sp_runtime::{Perbill, traits::{BlakeTwo256, IdentityLookup}, testing::Header};
 use sp_core::H256;
-use frame_support::{impl_outer_origin, impl_outer_dispatch, parameter_types, traits::{Get}, weights::Weight};
+use frame_support::{impl_outer_origin, impl_outer_event, impl_outer_dispatch, parameter_types, traits::{Get}, weights::Weight};
 use frame_system::{EnsureRoot};
 use node_primitives::{ChainId, BlockNumber};
+use crate as bridge_swap;
 use crate::{Module, Trait};
 
 pub(crate) type Balance = u128;
@@ -24,12 +25,25 @@ impl_outer_origin!{
 	pub enum Origin for Test where system = frame_system {}
 }
 
+impl_outer_event!{
+	pub enum TestEvent for Test {
+		frame_system<T>,
+		pallet_balances<T>,
+		rtoken_balances<T>,
+		xtoken_balances<T>,
+		bridge_relayers<T>,
+		bridge_common<T>,
+		bridge_swap<T>,
+	}
+}
+
 impl_outer_dispatch! {
 	pub enum Call for Test where origin: Origin {
 		frame_system::System,
 		balances::Balances,
 		rtoken_balances::RBalances,
 		xtoken_balances::XBalances,
+		bridge_relayers::BridgeRelayers,
 		bridge_common::BridgeCommon,
 		self::BridgeSwap,
 	}
@@ -59,7 +73,7 @@ impl frame_system::Trait for Test {
 	type AccountId = u64;
 	type Lookup = IdentityLookup<Self::AccountId>;
 	type Header = Header;
-	type Event = ();
+	type Event = TestEvent;
 	type BlockHashCount = BlockHashCount;
 	type MaximumBlockWeight = MaximumBlockWeight;
 	type DbWeight = ();
@@ -77,7 +91,7 @@ impl frame_system::Trait for Test {
 }
 
 thread_local! {
-	static EXISTENTIAL_DEPOSIT: RefCell<Balance> = RefCell::new(0);
+	pub(crate) static EXISTENTIAL_DEPOSIT: RefCell<Balance> = RefCell::new(0);
 }
 
 pub struct ExistentialDeposit;
@@ -91,43 +105,53 @@ impl pallet_balances::Trait for Test {
 	type MaxLocks = ();
 	type Balance = Balance;
 	type DustRemoval = ();
-	type Event = ();
+	type Event = TestEvent;
 	type ExistentialDeposit = ExistentialDeposit;
 	type AccountStore = System;
 	type WeightInfo = ();
 }
 
 impl xtoken_balances::Trait for Test {
-	type Event = ();
+	type Event = TestEvent;
 }
 
 impl rtoken_balances::Trait for Test {
-	type Event = ();
+	type Event = TestEvent;
+}
+
+impl bridge_relayers::Trait for Test {
+	type Event = TestEvent;
 }
 
 parameter_types! {
 	pub const ChainIdentity: ChainId = 1;
 	pub const ProposalLifetime: BlockNumber = 50;
+	pub const MaxChainFee: u128 = 1_000;
 }
 
 impl bridge_common::Trait for Test {
-	type Event = ();
+	type Event = TestEvent;
 	type AdminOrigin = EnsureRoot<Self::AccountId>;
 	type ChainIdentity = ChainIdentity;
 	type Proposal = Call;
 	type ProposalLifetime = ProposalLifetime;
+	type MaxChainFee = MaxChainFee;
+	type ProposalsPaused = bridge_swap::IsSwapPaused<Test>;
 }
 
 parameter_types! {
 	pub NativeTokenId: bridge_common::ResourceId = bridge_common::derive_resource_id(1, &blake2_128(b"FIS"));
+	pub const MaxMemoLen: u32 = 8;
 }
 
 impl Trait for Test {
+	type Event = TestEvent;
 	type Currency = Balances;
 	type RCurrency = RBalances;
 	type XCurrency = XBalances;
 	type NativeTokenId = NativeTokenId;
 	type BridgeOrigin = bridge_common::EnsureBridge<Test>;
+	type MaxMemoLen = MaxMemoLen;
 }
 
 pub fn new_test_ext() -> sp_io::TestExternalities {
@@ -146,6 +170,7 @@ pub type System = frame_system::Module<Test>;
 pub type Balances = pallet_balances::Module<Test>;
 pub type RBalances = rtoken_balances::Module<Test>;
 pub type XBalances = xtoken_balances::Module<Test>;
+pub type BridgeRelayers = bridge_relayers::Module<Test>;
 pub type BridgeCommon = bridge_common::Module<Test>;
 pub type BridgeSwap = Module<Test>;
 
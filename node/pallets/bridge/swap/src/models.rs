@@ -0,0 +1,32 @@
+use codec::{Decode, Encode};
+use sp_runtime::RuntimeDebug;
+
+#[derive(PartialEq, Eq, Clone, Copy, Encode, Decode, RuntimeDebug)]
+pub enum FeeMode {
+    /// flat fee set in bridge common chain fees
+    Flat,
+    /// basis points of the transfer amount
+    Percent(u32),
+}
+
+impl Default for FeeMode {
+    fn default() -> Self {
+        FeeMode::Flat
+    }
+}
+
+#[derive(PartialEq, Eq, Clone, Copy, Encode, Decode, RuntimeDebug)]
+pub enum FeeRoundingMode {
+    /// truncate percent fees
+    Down,
+    /// round percent fees up to the next unit
+    Up,
+    /// round percent fees to the nearest unit, halves up
+    Nearest,
+}
+
+impl Default for FeeRoundingMode {
+    fn default() -> Self {
+        FeeRoundingMode::Down
+    }
+}
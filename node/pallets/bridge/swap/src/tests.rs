@@ -16,49 +16,68 @@ use super::mock::{*, Call};
 use frame_support::{assert_ok, assert_noop, assert_err};
 use node_primitives::{ETH_CHAIN_ID, RSymbol};
 use sp_runtime::traits::BadOrigin;
+use sp_io::hashing::blake2_128;
+
+const FEES_RECIPIENT: u64 = 2;
+const CHAIN_FEES: u128 = 10;
+
+fn eth_address() -> Vec<u8> {
+	vec![11, 21, 31, 43, 88, 120, 43, 54, 55, 99, 54, 98, 23, 24, 54, 64, 29, 94, 26, 75]
+}
+
+fn setup_eth_chain() {
+	assert_ok!(BridgeCommon::whitelist_chain(Origin::root(), ETH_CHAIN_ID));
+	assert_ok!(BridgeCommon::set_proxy_accounts(Origin::root(), 40));
+	assert_ok!(BridgeCommon::set_chain_fees(Origin::signed(40), ETH_CHAIN_ID, CHAIN_FEES));
+	assert_ok!(BridgeCommon::set_fees_recipient_account(Origin::root(), FEES_RECIPIENT));
+}
+
+fn last_event() -> TestEvent {
+	System::events().pop().map(|e| e.event).expect("Event expected")
+}
 
 #[test]
 fn transfer_native_should_work() {
 	new_test_ext().execute_with(|| {
 		assert_noop!(
 			BridgeSwap::transfer_native(Origin::signed(42), 10, vec![11, 21], ETH_CHAIN_ID),
-			Error::<Test>::InvalidChainId,
+			bridge::Error::<Test>::InvalidChainId,
 		);
 
 		assert_ok!(BridgeCommon::set_is_pasued(Origin::root(), true));
 		assert_noop!(
 			BridgeSwap::transfer_native(Origin::signed(42), 10, vec![11, 21], ETH_CHAIN_ID),
-			Error::<Test>::ServicePaused,
+			bridge::Error::<Test>::ServicePaused,
 		);
 
 		assert_ok!(BridgeCommon::set_is_pasued(Origin::root(), false));
 		assert_noop!(
 			BridgeSwap::transfer_native(Origin::signed(42), 10, vec![11, 21], ETH_CHAIN_ID),
-			Error::<Test>::InvalidChainId,
+			bridge::Error::<Test>::InvalidChainId,
 		);
 
 		assert_ok!(BridgeCommon::whitelist_chain(Origin::root(), ETH_CHAIN_ID));
 		assert_noop!(
 			BridgeSwap::transfer_native(Origin::signed(42), 10, vec![11, 21], ETH_CHAIN_ID),
-			Error::<Test>::InvalidEthereumAddress,
-		);
-
-		let eth_address = vec![11, 21, 31, 43, 88, 120, 43, 54, 55, 99, 54, 98, 23, 24, 54, 64, 29, 94, 26, 75];
-		assert_noop!(
-			BridgeSwap::transfer_native(Origin::signed(42), 10, eth_address.clone(), ETH_CHAIN_ID),
-			Error::<Test>::InvalidChainFee,
+			bridge::Error::<Test>::InvalidChainFee,
 		);
 
 		assert_ok!(BridgeCommon::set_proxy_accounts(Origin::root(), 40));
 		let chain_fees = 10;
 		assert_ok!(BridgeCommon::set_chain_fees(Origin::signed(40), ETH_CHAIN_ID, chain_fees));
 		assert_noop!(
-			BridgeSwap::transfer_native(Origin::signed(42), 10, eth_address.clone(), ETH_CHAIN_ID),
-			Error::<Test>::InvalidFeesRecipientAccount,
+			BridgeSwap::transfer_native(Origin::signed(42), 10, vec![11, 21], ETH_CHAIN_ID),
+			bridge::Error::<Test>::InvalidFeesRecipientAccount,
 		);
 
 		let recipient_account = 2;
 		assert_ok!(BridgeCommon::set_fees_recipient_account(Origin::root(), recipient_account));
+		assert_noop!(
+			BridgeSwap::transfer_native(Origin::signed(42), 10, vec![11, 21], ETH_CHAIN_ID),
+			bridge::Error::<Test>::InvalidEthereumAddress,
+		);
+
+		let eth_address = vec![11, 21, 31, 43, 88, 120, 43, 54, 55, 99, 54, 98, 23, 24, 54, 64, 29, 94, 26, 75];
 		assert_noop!(
 			BridgeSwap::transfer_native(Origin::signed(42), 10, eth_address.clone(), ETH_CHAIN_ID),
 			pallet_balances::Error::<Test, _>::InsufficientBalance,
@@ -75,43 +94,57 @@ fn transfer_native_should_work() {
 	});
 }
 
+#[test]
+fn transfer_native_should_emit_event() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		setup_eth_chain();
+
+		assert_ok!(BridgeSwap::transfer_native(Origin::signed(1), 80, eth_address(), ETH_CHAIN_ID));
+		assert_eq!(
+			last_event(),
+			TestEvent::bridge_swap(RawEvent::NativeTransfer(1, ETH_CHAIN_ID, eth_address(), 80, CHAIN_FEES, 1)),
+		);
+	});
+}
+
 #[test]
 fn transfer_rtoken_should_work() {
 	new_test_ext().execute_with(|| {
 		assert_noop!(
 			BridgeSwap::transfer_rtoken(Origin::signed(42), RSymbol::RFIS, 100, vec![11, 21], ETH_CHAIN_ID),
-			Error::<Test>::InvalidChainId,
+			bridge::Error::<Test>::InvalidChainId,
 		);
 
 		assert_ok!(BridgeCommon::set_is_pasued(Origin::root(), true));
 		assert_noop!(
 			BridgeSwap::transfer_rtoken(Origin::signed(42), RSymbol::RFIS, 100, vec![11, 21], ETH_CHAIN_ID),
-			Error::<Test>::ServicePaused,
+			bridge::Error::<Test>::ServicePaused,
 		);
 		assert_ok!(BridgeCommon::set_is_pasued(Origin::root(), false));
 
 		assert_ok!(BridgeCommon::whitelist_chain(Origin::root(), ETH_CHAIN_ID));
 		assert_noop!(
 			BridgeSwap::transfer_rtoken(Origin::signed(42), RSymbol::RFIS, 100, vec![11, 21], ETH_CHAIN_ID),
-			Error::<Test>::InvalidEthereumAddress,
-		);
-
-		let eth_address = vec![11, 21, 31, 43, 88, 120, 43, 54, 55, 99, 54, 98, 23, 24, 54, 64, 29, 94, 26, 75];
-		assert_noop!(
-			BridgeSwap::transfer_rtoken(Origin::signed(42), RSymbol::RFIS, 100, eth_address.clone(), ETH_CHAIN_ID),
-			Error::<Test>::InvalidChainFee,
+			bridge::Error::<Test>::InvalidChainFee,
 		);
 
 		assert_ok!(BridgeCommon::set_proxy_accounts(Origin::root(), 40));
 		let chain_fees = 10;
 		assert_ok!(BridgeCommon::set_chain_fees(Origin::signed(40), ETH_CHAIN_ID, chain_fees));
 		assert_noop!(
-			BridgeSwap::transfer_rtoken(Origin::signed(42), RSymbol::RFIS, 100, eth_address.clone(), ETH_CHAIN_ID),
-			Error::<Test>::InvalidFeesRecipientAccount,
+			BridgeSwap::transfer_rtoken(Origin::signed(42), RSymbol::RFIS, 100, vec![11, 21], ETH_CHAIN_ID),
+			bridge::Error::<Test>::InvalidFeesRecipientAccount,
 		);
 
 		let recipient_account = 2;
 		assert_ok!(BridgeCommon::set_fees_recipient_account(Origin::root(), recipient_account));
+		assert_noop!(
+			BridgeSwap::transfer_rtoken(Origin::signed(42), RSymbol::RFIS, 100, vec![11, 21], ETH_CHAIN_ID),
+			bridge::Error::<Test>::InvalidEthereumAddress,
+		);
+
+		let eth_address = vec![11, 21, 31, 43, 88, 120, 43, 54, 55, 99, 54, 98, 23, 24, 54, 64, 29, 94, 26, 75];
 		assert_noop!(
 			BridgeSwap::transfer_rtoken(Origin::signed(42), RSymbol::RFIS, 100, eth_address.clone(), ETH_CHAIN_ID),
 			Error::<Test>::RsymbolNotMapped,
@@ -145,7 +178,7 @@ fn transfer_native_back_should_work() {
     new_test_ext().execute_with(|| {
         let recipient = RELAYER_A;
         let bridge_id: u64 = BridgeCommon::account_id();
-        let rid: ResourceId = [1; 32];
+        let rid: ResourceId = NativeTokenId::get();
 
 		assert_noop!(
 			BridgeSwap::transfer_native_back(Origin::signed(1), recipient, 100, rid),
@@ -154,13 +187,20 @@ fn transfer_native_back_should_work() {
 
 		assert_ok!(Balances::transfer(Origin::signed(1), bridge_id, 100));
 
+		assert_noop!(
+			BridgeSwap::transfer_native_back(Origin::signed(bridge_id), recipient, 100, [1; 32]),
+			Error::<Test>::ResourceNotMapped,
+		);
+
         // transfer_native_back
         assert_ok!(BridgeSwap::transfer_native_back(Origin::signed(bridge_id), recipient, 100, rid));
+        assert_eq!(Balances::free_balance(recipient), 100);
+        assert_eq!(Balances::free_balance(bridge_id), 0);
     })
 }
 
 fn make_transfer_proposal(to: u64, amount: u64) -> Call {
-    let rid: ResourceId = [1; 32];
+    let rid: ResourceId = NativeTokenId::get();
     Call::BridgeSwap(crate::Call::transfer_native_back(to, amount.into(), rid))
 }
 
@@ -169,16 +209,16 @@ fn transfer_native_back_proposal() {
     new_test_ext().execute_with(|| {
         let prop_id = 1;
         let src_id = 2;
-        let rid: ResourceId = [1; 32];
+        let rid: ResourceId = NativeTokenId::get();
         let resource = b"BridgeSwap.transfer_native_back".to_vec();
 		let proposal = make_transfer_proposal(RELAYER_A, 10);
 		
 		assert_ok!(Balances::transfer(Origin::signed(1), BridgeCommon::account_id(), 100));
 
-        assert_ok!(BridgeCommon::set_threshold(Origin::root(), TEST_THRESHOLD));
-        assert_ok!(BridgeCommon::add_relayer(Origin::root(), RELAYER_A));
-        assert_ok!(BridgeCommon::add_relayer(Origin::root(), RELAYER_B));
-        assert_ok!(BridgeCommon::add_relayer(Origin::root(), RELAYER_C));
+        assert_ok!(BridgeRelayers::set_threshold(Origin::root(), src_id, TEST_THRESHOLD));
+        assert_ok!(BridgeRelayers::add_relayer(Origin::root(), src_id, RELAYER_A));
+        assert_ok!(BridgeRelayers::add_relayer(Origin::root(), src_id, RELAYER_B));
+        assert_ok!(BridgeRelayers::add_relayer(Origin::root(), src_id, RELAYER_C));
         assert_ok!(BridgeCommon::whitelist_chain(Origin::root(), src_id));
         assert_ok!(BridgeCommon::add_resource(Origin::root(), rid, resource));
 
@@ -237,10 +277,10 @@ fn transfer_rtoken_back_proposal() {
 		let ac = BridgeCommon::account_id();
 		assert_ok!(RBalances::mint(&ac, sym, 100));
 
-        assert_ok!(BridgeCommon::set_threshold(Origin::root(), TEST_THRESHOLD));
-        assert_ok!(BridgeCommon::add_relayer(Origin::root(), RELAYER_A));
-        assert_ok!(BridgeCommon::add_relayer(Origin::root(), RELAYER_B));
-        assert_ok!(BridgeCommon::add_relayer(Origin::root(), RELAYER_C));
+        assert_ok!(BridgeRelayers::set_threshold(Origin::root(), src_id, TEST_THRESHOLD));
+        assert_ok!(BridgeRelayers::add_relayer(Origin::root(), src_id, RELAYER_A));
+        assert_ok!(BridgeRelayers::add_relayer(Origin::root(), src_id, RELAYER_B));
+        assert_ok!(BridgeRelayers::add_relayer(Origin::root(), src_id, RELAYER_C));
         assert_ok!(BridgeCommon::whitelist_chain(Origin::root(), src_id));
         assert_ok!(BridgeCommon::add_resource(Origin::root(), rid, resource));
 
@@ -292,3 +332,691 @@ fn transfer_rtoken_back_proposal() {
         assert_eq!(RBalances::free_balance(&ac, sym), 90);
     })
 }
+
+#[test]
+fn transfer_native_should_respect_chain_limits() {
+	new_test_ext().execute_with(|| {
+		setup_eth_chain();
+
+		assert_noop!(
+			BridgeSwap::set_chain_transfer_limits(Origin::signed(1), ETH_CHAIN_ID, 20, 50),
+			BadOrigin,
+		);
+		assert_noop!(
+			BridgeSwap::set_chain_transfer_limits(Origin::root(), ETH_CHAIN_ID, 50, 20),
+			Error::<Test>::InvalidTransferLimits,
+		);
+		assert_ok!(BridgeSwap::set_chain_transfer_limits(Origin::root(), ETH_CHAIN_ID, 20, 50));
+		assert_eq!(BridgeSwap::chain_transfer_limits(ETH_CHAIN_ID), Some((20, 50)));
+
+		assert_noop!(
+			BridgeSwap::transfer_native(Origin::signed(1), 19, eth_address(), ETH_CHAIN_ID),
+			Error::<Test>::AmountBelowMin,
+		);
+		assert_noop!(
+			BridgeSwap::transfer_native(Origin::signed(1), 51, eth_address(), ETH_CHAIN_ID),
+			Error::<Test>::AmountAboveMax,
+		);
+		assert_ok!(BridgeSwap::transfer_native(Origin::signed(1), 20, eth_address(), ETH_CHAIN_ID));
+		assert_ok!(BridgeSwap::transfer_native(Origin::signed(1), 50, eth_address(), ETH_CHAIN_ID));
+		assert_eq!(Balances::free_balance(BridgeCommon::account_id()), 70);
+
+		assert_ok!(BridgeSwap::remove_chain_transfer_limits(Origin::root(), ETH_CHAIN_ID));
+		assert_noop!(
+			BridgeSwap::transfer_native(Origin::signed(1), 51, eth_address(), ETH_CHAIN_ID),
+			pallet_balances::Error::<Test, _>::InsufficientBalance,
+		);
+	});
+}
+
+#[test]
+fn transfer_token_should_work() {
+	new_test_ext().execute_with(|| {
+		setup_eth_chain();
+		let rid: ResourceId = [1; 32];
+
+		assert_noop!(
+			BridgeSwap::transfer_token(Origin::signed(1), 10, eth_address(), ETH_CHAIN_ID, rid),
+			Error::<Test>::UnknownResource,
+		);
+		assert_ok!(BridgeCommon::map_resource_and_rsymbol(Origin::root(), rid, RSymbol::RFIS));
+
+		assert_noop!(
+			BridgeSwap::transfer_token(Origin::signed(1), 10, eth_address(), ETH_CHAIN_ID, rid),
+			Error::<Test>::InsufficientRbalance,
+		);
+		assert_ok!(RBalances::mint(&1, RSymbol::RFIS, 100));
+		assert_ok!(BridgeSwap::transfer_token(Origin::signed(1), 10, eth_address(), ETH_CHAIN_ID, rid));
+		assert_eq!(RBalances::free_balance(&BridgeCommon::account_id(), RSymbol::RFIS), 10);
+		assert_eq!(Balances::free_balance(&FEES_RECIPIENT), CHAIN_FEES);
+
+		// the native resource id needs no mapping
+		assert_ok!(BridgeSwap::transfer_token(Origin::signed(1), 20, eth_address(), ETH_CHAIN_ID, NativeTokenId::get()));
+		assert_eq!(Balances::free_balance(BridgeCommon::account_id()), 20);
+
+		assert_ok!(BridgeCommon::unmap_resource_and_rsymbol(Origin::root(), rid, RSymbol::RFIS));
+		assert_noop!(
+			BridgeSwap::transfer_token(Origin::signed(1), 10, eth_address(), ETH_CHAIN_ID, rid),
+			Error::<Test>::UnknownResource,
+		);
+	});
+}
+
+#[test]
+fn transfer_native_with_percent_fee_should_work() {
+	new_test_ext().execute_with(|| {
+		setup_eth_chain();
+
+		assert_noop!(
+			BridgeSwap::set_chain_fee_mode(Origin::root(), ETH_CHAIN_ID, FeeMode::Percent(10_001)),
+			Error::<Test>::InvalidFeeMode,
+		);
+		assert_eq!(BridgeSwap::chain_fee_mode(ETH_CHAIN_ID), FeeMode::Flat);
+		assert_eq!(BridgeSwap::native_fee(ETH_CHAIN_ID, 40, CHAIN_FEES), CHAIN_FEES);
+
+		// 5%
+		assert_ok!(BridgeSwap::set_chain_fee_mode(Origin::root(), ETH_CHAIN_ID, FeeMode::Percent(500)));
+		assert_eq!(BridgeSwap::native_fee(ETH_CHAIN_ID, 40, CHAIN_FEES), 2);
+		assert_ok!(BridgeSwap::transfer_native(Origin::signed(1), 40, eth_address(), ETH_CHAIN_ID));
+		assert_eq!(Balances::free_balance(BridgeCommon::account_id()), 40);
+		assert_eq!(Balances::free_balance(&FEES_RECIPIENT), 2);
+
+		// fees equal to amount
+		assert_ok!(BridgeSwap::set_chain_fee_mode(Origin::root(), ETH_CHAIN_ID, FeeMode::Percent(10_000)));
+		assert_ok!(BridgeSwap::transfer_native(Origin::signed(1), 20, eth_address(), ETH_CHAIN_ID));
+		assert_eq!(Balances::free_balance(BridgeCommon::account_id()), 60);
+		assert_eq!(Balances::free_balance(&FEES_RECIPIENT), 22);
+
+		assert_ok!(BridgeSwap::set_chain_fee_mode(Origin::root(), ETH_CHAIN_ID, FeeMode::Flat));
+		assert_eq!(BridgeSwap::native_fee(ETH_CHAIN_ID, 40, CHAIN_FEES), CHAIN_FEES);
+	});
+}
+
+#[test]
+fn swap_paused_should_block_transfers() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		setup_eth_chain();
+		let bridge_id: u64 = BridgeCommon::account_id();
+
+		assert_noop!(BridgeSwap::set_swap_paused(Origin::signed(1), true), BadOrigin);
+		assert_ok!(BridgeSwap::set_swap_paused(Origin::root(), true));
+		assert_eq!(last_event(), TestEvent::bridge_swap(RawEvent::SwapPausedChanged(true)));
+
+		assert_noop!(
+			BridgeSwap::transfer_native(Origin::signed(1), 10, eth_address(), ETH_CHAIN_ID),
+			Error::<Test>::BridgePaused,
+		);
+		assert_noop!(
+			BridgeSwap::transfer_rtoken(Origin::signed(1), RSymbol::RFIS, 10, eth_address(), ETH_CHAIN_ID),
+			Error::<Test>::BridgePaused,
+		);
+
+		assert_ok!(BridgeSwap::set_swap_paused(Origin::root(), false));
+		assert_eq!(last_event(), TestEvent::bridge_swap(RawEvent::SwapPausedChanged(false)));
+		assert_ok!(BridgeSwap::transfer_native(Origin::signed(1), 10, eth_address(), ETH_CHAIN_ID));
+		assert_ok!(BridgeSwap::transfer_native_back(Origin::signed(bridge_id), RELAYER_A, 10, NativeTokenId::get()));
+		assert_eq!(Balances::free_balance(RELAYER_A), 10);
+	});
+}
+
+#[test]
+fn swap_paused_should_reject_votes_until_unpaused() {
+	new_test_ext().execute_with(|| {
+		let prop_id = 1;
+		let src_id = 2;
+		let rid: ResourceId = NativeTokenId::get();
+		let resource = b"BridgeSwap.transfer_native_back".to_vec();
+		let proposal = make_transfer_proposal(RELAYER_A, 10);
+
+		assert_ok!(Balances::transfer(Origin::signed(1), BridgeCommon::account_id(), 100));
+		assert_ok!(BridgeRelayers::set_threshold(Origin::root(), src_id, TEST_THRESHOLD));
+		assert_ok!(BridgeRelayers::add_relayer(Origin::root(), src_id, RELAYER_A));
+		assert_ok!(BridgeRelayers::add_relayer(Origin::root(), src_id, RELAYER_B));
+		assert_ok!(BridgeRelayers::add_relayer(Origin::root(), src_id, RELAYER_C));
+		assert_ok!(BridgeCommon::whitelist_chain(Origin::root(), src_id));
+		assert_ok!(BridgeCommon::add_resource(Origin::root(), rid, resource));
+
+		// every relayer votes while paused, none of the votes is recorded
+		assert_ok!(BridgeSwap::set_swap_paused(Origin::root(), true));
+		for relayer in [RELAYER_A, RELAYER_B, RELAYER_C].iter() {
+			assert_noop!(
+				BridgeCommon::acknowledge_proposal(Origin::signed(*relayer), prop_id, src_id, rid, Box::new(proposal.clone())),
+				bridge::Error::<Test>::ServicePaused,
+			);
+		}
+		assert_eq!(BridgeCommon::votes(src_id, (prop_id, proposal.clone())), None);
+
+		// so the deposit can still be executed once unpaused
+		assert_ok!(BridgeSwap::set_swap_paused(Origin::root(), false));
+		assert_ok!(BridgeCommon::acknowledge_proposal(Origin::signed(RELAYER_A), prop_id, src_id, rid, Box::new(proposal.clone())));
+		assert_ok!(BridgeCommon::acknowledge_proposal(Origin::signed(RELAYER_C), prop_id, src_id, rid, Box::new(proposal.clone())));
+		assert_eq!(
+			BridgeCommon::votes(src_id, (prop_id, proposal.clone())).unwrap().status,
+			bridge::ProposalStatus::Executed,
+		);
+		assert_eq!(Balances::free_balance(RELAYER_A), 10);
+		assert_eq!(Balances::free_balance(BridgeCommon::account_id()), 90);
+	});
+}
+
+#[test]
+fn transfer_native_should_respect_volume_limit() {
+	new_test_ext().execute_with(|| {
+		setup_eth_chain();
+		assert_ok!(BridgeSwap::set_chain_fee_mode(Origin::root(), ETH_CHAIN_ID, FeeMode::Percent(0)));
+		assert_ok!(Balances::transfer(Origin::signed(1), 42, 25));
+
+		assert_noop!(
+			BridgeSwap::set_daily_volume_limit(Origin::root(), 30, 0),
+			Error::<Test>::InvalidVolumeWindow,
+		);
+		assert_ok!(BridgeSwap::set_daily_volume_limit(Origin::root(), 30, 10));
+
+		System::set_block_number(1);
+		assert_ok!(BridgeSwap::transfer_native(Origin::signed(1), 20, eth_address(), ETH_CHAIN_ID));
+		assert_eq!(BridgeSwap::account_volume(1), Some((1, 20)));
+		assert_noop!(
+			BridgeSwap::transfer_native(Origin::signed(1), 11, eth_address(), ETH_CHAIN_ID),
+			Error::<Test>::VolumeLimitExceeded,
+		);
+		// other accounts have their own volume
+		assert_ok!(BridgeSwap::transfer_native(Origin::signed(42), 20, eth_address(), ETH_CHAIN_ID));
+
+		System::set_block_number(10);
+		assert_ok!(BridgeSwap::transfer_native(Origin::signed(1), 10, eth_address(), ETH_CHAIN_ID));
+		assert_eq!(BridgeSwap::account_volume(1), Some((1, 30)));
+
+		// window rolls over
+		System::set_block_number(11);
+		assert_ok!(BridgeSwap::transfer_native(Origin::signed(1), 5, eth_address(), ETH_CHAIN_ID));
+		assert_eq!(BridgeSwap::account_volume(1), Some((11, 5)));
+
+		// zero limit disables the check
+		assert_ok!(BridgeSwap::set_daily_volume_limit(Origin::root(), 0, 0));
+		assert_ok!(BridgeSwap::transfer_native(Origin::signed(1), 31, eth_address(), ETH_CHAIN_ID));
+		assert_eq!(BridgeSwap::account_volume(1), Some((11, 5)));
+	});
+}
+
+#[test]
+fn transfer_native_should_check_recipient_len() {
+	new_test_ext().execute_with(|| {
+		let chain_id = 5;
+		assert_ok!(BridgeCommon::whitelist_chain(Origin::root(), chain_id));
+		assert_ok!(BridgeCommon::set_proxy_accounts(Origin::root(), 40));
+		assert_ok!(BridgeCommon::set_chain_fees(Origin::signed(40), chain_id, CHAIN_FEES));
+		assert_ok!(BridgeCommon::set_fees_recipient_account(Origin::root(), FEES_RECIPIENT));
+
+		assert_noop!(
+			BridgeSwap::set_chain_recipient_len(Origin::root(), chain_id, 33, 32),
+			Error::<Test>::InvalidRecipientLen,
+		);
+		assert_ok!(BridgeSwap::set_chain_recipient_len(Origin::root(), chain_id, 32, 32));
+
+		assert_noop!(
+			BridgeSwap::transfer_native(Origin::signed(1), 10, vec![1; 31], chain_id),
+			Error::<Test>::InvalidRecipient,
+		);
+		assert_noop!(
+			BridgeSwap::transfer_native(Origin::signed(1), 10, vec![1; 33], chain_id),
+			Error::<Test>::InvalidRecipient,
+		);
+		assert_ok!(BridgeSwap::transfer_native(Origin::signed(1), 10, vec![1; 32], chain_id));
+
+		assert_ok!(BridgeSwap::remove_chain_recipient_len(Origin::root(), chain_id));
+		assert_ok!(BridgeSwap::transfer_native(Origin::signed(1), 10, vec![1; 31], chain_id));
+	});
+}
+
+#[test]
+fn failed_transfer_native_should_restore_balances() {
+	new_test_ext().execute_with(|| {
+		setup_eth_chain();
+		EXISTENTIAL_DEPOSIT.with(|v| *v.borrow_mut() = 5);
+
+		// moving the fee out would leave the new bridge account below the existential deposit
+		assert_noop!(
+			BridgeSwap::transfer_native(Origin::signed(1), 3, eth_address(), ETH_CHAIN_ID),
+			pallet_balances::Error::<Test, _>::KeepAlive,
+		);
+		assert_eq!(Balances::free_balance(1), 100);
+		assert_eq!(Balances::free_balance(BridgeCommon::account_id()), 0);
+		assert_eq!(Balances::free_balance(&FEES_RECIPIENT), 0);
+	});
+}
+
+#[test]
+fn transfer_native_with_memo_should_work() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		setup_eth_chain();
+
+		assert_noop!(
+			BridgeSwap::transfer_native_with_memo(Origin::signed(1), 10, eth_address(), ETH_CHAIN_ID, vec![1; 9]),
+			Error::<Test>::ParamsErr,
+		);
+
+		let memo = vec![1; 8];
+		assert_ok!(BridgeSwap::transfer_native_with_memo(Origin::signed(1), 10, eth_address(), ETH_CHAIN_ID, memo.clone()));
+		assert_eq!(
+			last_event(),
+			TestEvent::bridge_swap(RawEvent::NativeTransferWithMemo(1, ETH_CHAIN_ID, eth_address(), 10, CHAIN_FEES, 1, memo)),
+		);
+		assert_eq!(Balances::free_balance(BridgeCommon::account_id()), 10);
+
+		assert_ok!(BridgeSwap::transfer_native_with_memo(Origin::signed(1), 10, eth_address(), ETH_CHAIN_ID, vec![]));
+		assert_eq!(
+			last_event(),
+			TestEvent::bridge_swap(RawEvent::NativeTransferWithMemo(1, ETH_CHAIN_ID, eth_address(), 10, CHAIN_FEES, 2, vec![])),
+		);
+	});
+}
+
+#[test]
+fn fees_should_be_split_among_recipients() {
+	new_test_ext().execute_with(|| {
+		setup_eth_chain();
+		let (recipient_a, recipient_b) = (50u64, 51u64);
+
+		assert_noop!(
+			BridgeSwap::set_fee_recipients(Origin::root(), vec![(recipient_a, 3_000), (recipient_b, 6_000)]),
+			Error::<Test>::InvalidFeeRecipients,
+		);
+		assert_noop!(
+			BridgeSwap::set_fee_recipients(Origin::root(), vec![(recipient_a, 5_000), (BridgeCommon::account_id(), 5_000)]),
+			bridge::Error::<Test>::InvalidFeesAccount,
+		);
+		let too_many: Vec<(u64, u32)> = (0..MAX_FEE_RECIPIENTS as u64 + 1).map(|i| (100 + i, 0)).collect();
+		assert_noop!(
+			BridgeSwap::set_fee_recipients(Origin::root(), too_many),
+			Error::<Test>::TooManyFeeRecipients,
+		);
+		assert_ok!(BridgeSwap::set_fee_recipients(Origin::root(), vec![(recipient_a, 3_333), (recipient_b, 6_667)]));
+
+		// 10 * 33.33% = 3.333 and 10 * 66.67% = 6.667, the dust goes to the first recipient
+		assert_ok!(BridgeSwap::transfer_native(Origin::signed(1), 10, eth_address(), ETH_CHAIN_ID));
+		assert_eq!(Balances::free_balance(recipient_a), 4);
+		assert_eq!(Balances::free_balance(recipient_b), 6);
+		assert_eq!(Balances::free_balance(&FEES_RECIPIENT), 0);
+		assert_eq!(Balances::free_balance(BridgeCommon::account_id()), 10);
+
+		// falls back to the fees recipient account
+		assert_ok!(BridgeSwap::set_fee_recipients(Origin::root(), vec![]));
+		assert_ok!(BridgeSwap::transfer_native(Origin::signed(1), 10, eth_address(), ETH_CHAIN_ID));
+		assert_eq!(Balances::free_balance(&FEES_RECIPIENT), CHAIN_FEES);
+	});
+}
+
+#[test]
+fn fee_exempt_account_should_pay_no_fee() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		setup_eth_chain();
+		let exempt = 42u64;
+		assert_ok!(Balances::transfer(Origin::signed(1), exempt, 40));
+
+		assert_noop!(
+			BridgeSwap::add_fee_exempt(Origin::signed(exempt), exempt),
+			BadOrigin,
+		);
+		assert_ok!(BridgeSwap::add_fee_exempt(Origin::root(), exempt));
+
+		assert_noop!(
+			BridgeSwap::transfer_native(Origin::signed(exempt), 0, eth_address(), ETH_CHAIN_ID),
+			Error::<Test>::AmountZero,
+		);
+		assert_ok!(BridgeSwap::transfer_native(Origin::signed(exempt), 20, eth_address(), ETH_CHAIN_ID));
+		assert_eq!(Balances::free_balance(exempt), 20);
+		assert_eq!(Balances::free_balance(&FEES_RECIPIENT), 0);
+		assert_eq!(
+			last_event(),
+			TestEvent::bridge_swap(RawEvent::NativeTransfer(exempt, ETH_CHAIN_ID, eth_address(), 20, 0, 1)),
+		);
+
+		// other accounts still pay the fee
+		assert_ok!(BridgeSwap::transfer_native(Origin::signed(1), 20, eth_address(), ETH_CHAIN_ID));
+		assert_eq!(Balances::free_balance(&FEES_RECIPIENT), CHAIN_FEES);
+
+		assert_ok!(BridgeSwap::remove_fee_exempt(Origin::root(), exempt));
+		assert_ok!(BridgeSwap::transfer_native(Origin::signed(exempt), 1, eth_address(), ETH_CHAIN_ID));
+		assert_eq!(Balances::free_balance(&FEES_RECIPIENT), 2 * CHAIN_FEES);
+	});
+}
+
+#[test]
+fn transfer_native_should_reject_too_large_amount() {
+	new_test_ext().execute_with(|| {
+		setup_eth_chain();
+
+		assert_noop!(
+			BridgeSwap::transfer_native(Origin::signed(1), u128::MAX, eth_address(), ETH_CHAIN_ID),
+			Error::<Test>::AmountTooLarge,
+		);
+		assert_noop!(
+			BridgeSwap::transfer_native(Origin::signed(1), u128::MAX - CHAIN_FEES + 1, eth_address(), ETH_CHAIN_ID),
+			Error::<Test>::AmountTooLarge,
+		);
+		// the largest amount that fits only fails for lack of balance
+		assert_noop!(
+			BridgeSwap::transfer_native(Origin::signed(1), u128::MAX - CHAIN_FEES, eth_address(), ETH_CHAIN_ID),
+			pallet_balances::Error::<Test, _>::InsufficientBalance,
+		);
+	});
+}
+
+#[test]
+fn chain_fee_and_whitelist_queries_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(BridgeSwap::chain_transfer_fee(ETH_CHAIN_ID), None);
+		assert!(!BridgeSwap::is_chain_whitelisted(ETH_CHAIN_ID));
+
+		setup_eth_chain();
+
+		assert_eq!(BridgeSwap::chain_transfer_fee(ETH_CHAIN_ID), Some(CHAIN_FEES));
+		assert!(BridgeSwap::is_chain_whitelisted(ETH_CHAIN_ID));
+		assert_eq!(BridgeSwap::whitelisted_chains(), vec![ETH_CHAIN_ID]);
+		assert_ok!(BridgeCommon::remove_whitelist_chain(Origin::root(), ETH_CHAIN_ID));
+		assert_eq!(BridgeSwap::whitelisted_chains(), vec![]);
+	});
+}
+
+#[test]
+fn estimate_transfer_native_should_match_transfer() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			BridgeSwap::estimate_transfer_native(&1, 20, ETH_CHAIN_ID),
+			bridge::Error::<Test>::InvalidChainId,
+		);
+		assert_ok!(BridgeCommon::whitelist_chain(Origin::root(), ETH_CHAIN_ID));
+		assert_noop!(
+			BridgeSwap::estimate_transfer_native(&1, 20, ETH_CHAIN_ID),
+			bridge::Error::<Test>::InvalidChainFee,
+		);
+
+		assert_ok!(BridgeCommon::set_proxy_accounts(Origin::root(), 40));
+		assert_ok!(BridgeCommon::set_chain_fees(Origin::signed(40), ETH_CHAIN_ID, CHAIN_FEES));
+		assert_ok!(BridgeCommon::set_fees_recipient_account(Origin::root(), FEES_RECIPIENT));
+		assert_noop!(
+			BridgeSwap::estimate_transfer_native(&1, 0, ETH_CHAIN_ID),
+			Error::<Test>::AmountZero,
+		);
+		assert_ok!(BridgeSwap::set_chain_fee_mode(Origin::root(), ETH_CHAIN_ID, FeeMode::Percent(500)));
+		assert_eq!(BridgeSwap::estimate_transfer_native(&1, 40, ETH_CHAIN_ID), Ok((2, 40)));
+
+		assert_ok!(BridgeSwap::transfer_native(Origin::signed(1), 40, eth_address(), ETH_CHAIN_ID));
+		assert_eq!(Balances::free_balance(1), 100 - 40 - 2);
+		assert_eq!(Balances::free_balance(BridgeCommon::account_id()), 40);
+		assert_eq!(Balances::free_balance(&FEES_RECIPIENT), 2);
+	});
+}
+
+#[test]
+fn native_transfer_event_should_carry_deposit_nonce() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		setup_eth_chain();
+		let other_chain: ChainId = 5;
+		assert_ok!(BridgeCommon::whitelist_chain(Origin::root(), other_chain));
+		assert_ok!(BridgeCommon::set_chain_fees(Origin::signed(40), other_chain, 0));
+
+		assert_ok!(BridgeSwap::transfer_native(Origin::signed(1), 5, eth_address(), ETH_CHAIN_ID));
+		assert_ok!(BridgeSwap::transfer_native(Origin::signed(1), 5, eth_address(), ETH_CHAIN_ID));
+		assert_eq!(
+			last_event(),
+			TestEvent::bridge_swap(RawEvent::NativeTransfer(1, ETH_CHAIN_ID, eth_address(), 5, CHAIN_FEES, 2)),
+		);
+		assert_eq!(BridgeCommon::chains(ETH_CHAIN_ID), Some(2));
+
+		assert_ok!(BridgeSwap::transfer_native(Origin::signed(1), 5, vec![1], other_chain));
+		assert_eq!(
+			last_event(),
+			TestEvent::bridge_swap(RawEvent::NativeTransfer(1, other_chain, vec![1], 5, 0, 1)),
+		);
+		assert_eq!(BridgeCommon::chains(other_chain), Some(1));
+
+		// failed transfers leave the nonce untouched
+		assert_noop!(
+			BridgeSwap::transfer_native(Origin::signed(1), 100, eth_address(), ETH_CHAIN_ID),
+			pallet_balances::Error::<Test, _>::InsufficientBalance,
+		);
+		assert_eq!(BridgeCommon::chains(ETH_CHAIN_ID), Some(2));
+	});
+}
+
+#[test]
+fn compute_fees_and_amount_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			BridgeSwap::compute_fees_and_amount(&1, 0, ETH_CHAIN_ID, CHAIN_FEES),
+			Error::<Test>::AmountZero,
+		);
+		assert_ok!(BridgeSwap::set_min_swap_amount(Origin::root(), 5));
+		assert_noop!(
+			BridgeSwap::compute_fees_and_amount(&1, 4, ETH_CHAIN_ID, CHAIN_FEES),
+			Error::<Test>::SwapAmountTooSmall,
+		);
+		assert_eq!(BridgeSwap::compute_fees_and_amount(&1, 5, ETH_CHAIN_ID, CHAIN_FEES), Ok((CHAIN_FEES, 5)));
+		assert_noop!(
+			BridgeSwap::compute_fees_and_amount(&1, u128::MAX, ETH_CHAIN_ID, CHAIN_FEES),
+			Error::<Test>::AmountTooLarge,
+		);
+		assert_eq!(BridgeSwap::compute_fees_and_amount(&1, 20, ETH_CHAIN_ID, CHAIN_FEES), Ok((CHAIN_FEES, 20)));
+
+		assert_ok!(BridgeSwap::set_chain_fee_mode(Origin::root(), ETH_CHAIN_ID, FeeMode::Percent(1_000)));
+		assert_eq!(BridgeSwap::compute_fees_and_amount(&1, 20, ETH_CHAIN_ID, CHAIN_FEES), Ok((2, 20)));
+
+		assert_ok!(BridgeSwap::add_fee_exempt(Origin::root(), 1));
+		assert_eq!(BridgeSwap::compute_fees_and_amount(&1, 20, ETH_CHAIN_ID, CHAIN_FEES), Ok((0, 20)));
+	});
+}
+
+#[test]
+fn chain_native_resource_should_override_native_token_id() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		setup_eth_chain();
+		let other_chain: ChainId = 5;
+		let other_resource = bridge::derive_resource_id(other_chain, &blake2_128(b"FIS"));
+		assert_ok!(BridgeCommon::whitelist_chain(Origin::root(), other_chain));
+		assert_ok!(BridgeCommon::set_chain_fees(Origin::signed(40), other_chain, 0));
+
+		assert_noop!(
+			BridgeSwap::set_chain_native_resource(Origin::root(), other_chain, [0; 32]),
+			Error::<Test>::InvalidResourceId,
+		);
+		let rtoken_resource: ResourceId = [1; 32];
+		assert_ok!(BridgeCommon::map_resource_and_rsymbol(Origin::root(), rtoken_resource, RSymbol::RFIS));
+		assert_noop!(
+			BridgeSwap::set_chain_native_resource(Origin::root(), other_chain, rtoken_resource),
+			Error::<Test>::ResourceAlreadyMapped,
+		);
+		assert_ok!(BridgeSwap::set_chain_native_resource(Origin::root(), other_chain, other_resource));
+		assert!(BridgeSwap::native_resources(other_resource));
+		assert_noop!(
+			BridgeSwap::set_chain_native_resource(Origin::root(), ETH_CHAIN_ID, other_resource),
+			Error::<Test>::ResourceAlreadyMapped,
+		);
+		assert_eq!(BridgeSwap::native_resource(ETH_CHAIN_ID), NativeTokenId::get());
+		assert_eq!(BridgeSwap::native_resource(other_chain), other_resource);
+
+		let fungible_transfer = |dest_id, nonce, resource_id, to: Vec<u8>| TestEvent::bridge_common(
+			bridge::RawEvent::FungibleTransfer(1, dest_id, nonce, resource_id, U256::from(5), to)
+		);
+		assert_ok!(BridgeSwap::transfer_native(Origin::signed(1), 5, eth_address(), ETH_CHAIN_ID));
+		assert_ok!(BridgeSwap::transfer_native(Origin::signed(1), 5, vec![1], other_chain));
+		let events: Vec<TestEvent> = System::events().into_iter().map(|r| r.event).collect();
+		assert!(events.contains(&fungible_transfer(ETH_CHAIN_ID, 1, NativeTokenId::get(), eth_address())));
+		assert!(events.contains(&fungible_transfer(other_chain, 1, other_resource, vec![1])));
+
+		// the overridden rId swaps back as native token
+		let bridge_id: u64 = BridgeCommon::account_id();
+		assert_eq!(Balances::free_balance(bridge_id), 10);
+		assert_ok!(BridgeSwap::transfer_native_back(Origin::signed(bridge_id), 3, 5, other_resource));
+		assert_eq!(Balances::free_balance(3), 5);
+		assert_eq!(Balances::free_balance(bridge_id), 5);
+
+		// transfer_token bridges native token under the rId of the chain
+		assert_ok!(BridgeSwap::transfer_token(Origin::signed(1), 5, vec![1], other_chain, other_resource));
+		assert_eq!(Balances::free_balance(bridge_id), 10);
+		assert_noop!(
+			BridgeSwap::transfer_token(Origin::signed(1), 5, vec![1], other_chain, NativeTokenId::get()),
+			Error::<Test>::UnknownResource,
+		);
+
+		assert_ok!(BridgeSwap::remove_chain_native_resource(Origin::root(), other_chain));
+		assert_eq!(BridgeSwap::native_resource(other_chain), NativeTokenId::get());
+		assert!(!BridgeSwap::native_resources(other_resource));
+		assert_noop!(
+			BridgeSwap::transfer_native_back(Origin::signed(bridge_id), 3, 5, other_resource),
+			Error::<Test>::ResourceNotMapped,
+		);
+	});
+}
+
+#[test]
+fn failed_transfer_native_should_leave_no_funds_in_bridge() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		setup_eth_chain();
+		let (recipient_a, recipient_b) = (50u64, 51u64);
+		assert_ok!(BridgeSwap::set_fee_recipients(Origin::root(), vec![(recipient_a, 7_000), (recipient_b, 3_000)]));
+		assert_ok!(BridgeSwap::set_daily_volume_limit(Origin::root(), 50, 10));
+		EXISTENTIAL_DEPOSIT.with(|v| *v.borrow_mut() = 5);
+		let events = System::events().len();
+
+		// fails on the last fee share, after amount and fee reached the bridge account
+		// and the first share was paid out
+		assert_noop!(
+			BridgeSwap::transfer_native(Origin::signed(1), 20, eth_address(), ETH_CHAIN_ID),
+			pallet_balances::Error::<Test, _>::ExistentialDeposit,
+		);
+		assert_eq!(Balances::free_balance(1), 100);
+		assert_eq!(Balances::free_balance(BridgeCommon::account_id()), 0);
+		assert_eq!(Balances::free_balance(recipient_a), 0);
+		assert_eq!(BridgeSwap::account_volume(1), None);
+		assert_eq!(BridgeCommon::chains(ETH_CHAIN_ID), Some(0));
+		assert_eq!(System::events().len(), events);
+	});
+}
+
+#[test]
+fn bridge_account_balance_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(BridgeSwap::bridge_account_balance(), 0);
+		assert_ok!(Balances::transfer(Origin::signed(1), BridgeCommon::account_id(), 30));
+
+		assert_eq!(BridgeSwap::bridge_account_balance(), 30);
+	});
+}
+
+#[test]
+fn min_absolute_fee_should_floor_native_fee() {
+	new_test_ext().execute_with(|| {
+		setup_eth_chain();
+		assert_ok!(BridgeSwap::set_chain_fee_mode(Origin::root(), ETH_CHAIN_ID, FeeMode::Percent(100)));
+		assert_eq!(BridgeSwap::native_fee(ETH_CHAIN_ID, 40, CHAIN_FEES), 0);
+
+		assert_noop!(BridgeSwap::set_min_absolute_fee(Origin::signed(1), 3), BadOrigin);
+		assert_noop!(
+			BridgeSwap::set_min_absolute_fee(Origin::root(), MaxChainFee::get() + 1),
+			bridge::Error::<Test>::ChainFeesTooHigh,
+		);
+		assert_ok!(BridgeSwap::set_min_absolute_fee(Origin::root(), MaxChainFee::get()));
+		assert_ok!(BridgeSwap::set_min_absolute_fee(Origin::root(), 3));
+		assert_eq!(BridgeSwap::native_fee(ETH_CHAIN_ID, 40, CHAIN_FEES), 3);
+		// the floor does not lower larger fees
+		assert_eq!(BridgeSwap::native_fee(ETH_CHAIN_ID, 1_000, CHAIN_FEES), 10);
+
+		assert_ok!(BridgeSwap::transfer_native(Origin::signed(1), 40, eth_address(), ETH_CHAIN_ID));
+		assert_eq!(Balances::free_balance(1), 100 - 40 - 3);
+		assert_eq!(Balances::free_balance(&FEES_RECIPIENT), 3);
+
+		assert_ok!(BridgeSwap::set_min_absolute_fee(Origin::root(), 0));
+		assert_eq!(BridgeSwap::native_fee(ETH_CHAIN_ID, 40, CHAIN_FEES), 0);
+	});
+}
+
+#[test]
+fn fee_rounding_mode_should_work() {
+	new_test_ext().execute_with(|| {
+		setup_eth_chain();
+		// 1.5% of 30 is 0.45, of 110 is 1.65
+		assert_ok!(BridgeSwap::set_chain_fee_mode(Origin::root(), ETH_CHAIN_ID, FeeMode::Percent(150)));
+
+		assert_eq!(BridgeSwap::fee_rounding(), FeeRoundingMode::Down);
+		assert_eq!(BridgeSwap::native_fee(ETH_CHAIN_ID, 30, CHAIN_FEES), 0);
+		assert_eq!(BridgeSwap::native_fee(ETH_CHAIN_ID, 110, CHAIN_FEES), 1);
+
+		assert_noop!(BridgeSwap::set_fee_rounding_mode(Origin::signed(1), FeeRoundingMode::Up), BadOrigin);
+		assert_ok!(BridgeSwap::set_fee_rounding_mode(Origin::root(), FeeRoundingMode::Up));
+		assert_eq!(BridgeSwap::native_fee(ETH_CHAIN_ID, 30, CHAIN_FEES), 1);
+		assert_eq!(BridgeSwap::native_fee(ETH_CHAIN_ID, 110, CHAIN_FEES), 2);
+		assert_eq!(BridgeSwap::native_fee(ETH_CHAIN_ID, 200, CHAIN_FEES), 3);
+
+		assert_ok!(BridgeSwap::set_fee_rounding_mode(Origin::root(), FeeRoundingMode::Nearest));
+		assert_eq!(BridgeSwap::native_fee(ETH_CHAIN_ID, 30, CHAIN_FEES), 0);
+		assert_eq!(BridgeSwap::native_fee(ETH_CHAIN_ID, 110, CHAIN_FEES), 2);
+		assert_eq!(BridgeSwap::native_fee(ETH_CHAIN_ID, 100, CHAIN_FEES), 2);
+
+		// no overflow at the top of the range
+		assert_ok!(BridgeSwap::set_chain_fee_mode(Origin::root(), ETH_CHAIN_ID, FeeMode::Percent(10_000)));
+		assert_eq!(BridgeSwap::native_fee(ETH_CHAIN_ID, u128::MAX, CHAIN_FEES), u128::MAX);
+	});
+}
+
+#[test]
+fn transfer_native_with_max_fee_should_work() {
+	new_test_ext().execute_with(|| {
+		setup_eth_chain();
+
+		assert_ok!(BridgeSwap::transfer_native_with_max_fee(Origin::signed(1), 20, eth_address(), ETH_CHAIN_ID, CHAIN_FEES));
+		assert_eq!(Balances::free_balance(1), 70);
+		assert_eq!(Balances::free_balance(&FEES_RECIPIENT), CHAIN_FEES);
+
+		// fee raised after the wallet read it
+		assert_ok!(BridgeCommon::set_chain_fees(Origin::signed(40), ETH_CHAIN_ID, CHAIN_FEES + 1));
+		assert_noop!(
+			BridgeSwap::transfer_native_with_max_fee(Origin::signed(1), 20, eth_address(), ETH_CHAIN_ID, CHAIN_FEES),
+			Error::<Test>::FeeExceedsMax,
+		);
+		assert_ok!(BridgeSwap::transfer_native(Origin::signed(1), 20, eth_address(), ETH_CHAIN_ID));
+	});
+}
+
+#[test]
+fn total_bridged_out_should_accumulate_per_chain() {
+	new_test_ext().execute_with(|| {
+		setup_eth_chain();
+		let other_chain: ChainId = 5;
+		assert_ok!(BridgeCommon::whitelist_chain(Origin::root(), other_chain));
+		assert_ok!(BridgeCommon::set_chain_fees(Origin::signed(40), other_chain, 0));
+
+		assert_ok!(BridgeSwap::transfer_native(Origin::signed(1), 5, eth_address(), ETH_CHAIN_ID));
+		assert_ok!(BridgeSwap::transfer_native(Origin::signed(1), 7, eth_address(), ETH_CHAIN_ID));
+		assert_ok!(BridgeSwap::transfer_native(Origin::signed(1), 3, vec![1], other_chain));
+		assert_eq!(BridgeSwap::total_bridged_out(ETH_CHAIN_ID), U256::from(12));
+		assert_eq!(BridgeSwap::total_bridged_out(other_chain), U256::from(3));
+	});
+}
+
+#[test]
+fn transfer_native_should_respect_min_swap_amount() {
+	new_test_ext().execute_with(|| {
+		setup_eth_chain();
+		assert_ok!(BridgeSwap::transfer_native(Origin::signed(1), 1, eth_address(), ETH_CHAIN_ID));
+
+		assert_noop!(BridgeSwap::set_min_swap_amount(Origin::signed(1), 5), BadOrigin);
+		assert_ok!(BridgeSwap::set_min_swap_amount(Origin::root(), 5));
+		assert_noop!(
+			BridgeSwap::transfer_native(Origin::signed(1), 4, eth_address(), ETH_CHAIN_ID),
+			Error::<Test>::SwapAmountTooSmall,
+		);
+		assert_noop!(
+			BridgeSwap::estimate_transfer_native(&1, 4, ETH_CHAIN_ID),
+			Error::<Test>::SwapAmountTooSmall,
+		);
+		assert_ok!(BridgeSwap::transfer_native(Origin::signed(1), 5, eth_address(), ETH_CHAIN_ID));
+		assert_eq!(Balances::free_balance(BridgeCommon::account_id()), 6);
+	});
+}
@@ -31,7 +31,7 @@ use codec::{Encode, Decode};
 use sp_core::{
 	crypto::KeyTypeId,
 	u32_trait::{_1, _2, _3, _4},
-	OpaqueMetadata,
+	OpaqueMetadata, U256,
 };
 use sp_io::hashing::blake2_128;
 pub use node_primitives::{AccountId, Signature};
@@ -39,7 +39,7 @@ use node_primitives::{AccountIndex, Balance, BlockNumber, Hash, Index, Moment, C
 use sp_api::impl_runtime_apis;
 use sp_runtime::{
 	Permill, Perbill, Perquintill, Percent, ApplyExtrinsicResult,
-	impl_opaque_keys, generic, create_runtime_str, ModuleId, FixedPointNumber,
+	impl_opaque_keys, generic, create_runtime_str, ModuleId, FixedPointNumber, DispatchError,
 };
 use sp_runtime::curve::PiecewiseLinear;
 use sp_runtime::transaction_validity::{TransactionValidity, TransactionSource, TransactionPriority};
@@ -102,7 +102,7 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	// and set impl_version to 0. If only runtime
 	// implementation changes and behavior does not, then leave spec_version as
 	// is and increment impl_version.
-	spec_version: 20,
+	spec_version: 21,
 	impl_version: 0,
 	apis: RUNTIME_API_VERSIONS,
 	transaction_version: 1,
@@ -851,6 +851,7 @@ impl pallet_vesting::Trait for Runtime {
 parameter_types! {
 	pub const ChainIdentity: ChainId = 1;
 	pub const ProposalLifetime: BlockNumber = 201600;
+	pub const MaxChainFee: Balance = 10_000 * DOLLARS;
 }
 
 impl bridge_relayers::Trait for Runtime {
@@ -863,18 +864,23 @@ impl bridge_common::Trait for Runtime {
 	type Proposal = Call;
 	type ChainIdentity = ChainIdentity;
 	type ProposalLifetime = ProposalLifetime;
+	type MaxChainFee = MaxChainFee;
+	type ProposalsPaused = bridge_swap::IsSwapPaused<Runtime>;
 }
 
 parameter_types! {
 	pub NativeTokenId: bridge_common::ResourceId = bridge_common::derive_resource_id(1, &blake2_128(b"FIS"));
+	pub const MaxMemoLen: u32 = 256;
 }
 
 impl bridge_swap::Trait for Runtime {
+	type Event = Event;
 	type Currency = Balances;
 	type RCurrency = RBalances;
 	type XCurrency = XBalances;
 	type BridgeOrigin = bridge_common::EnsureBridge<Runtime>;
 	type NativeTokenId = NativeTokenId;
+	type MaxMemoLen = MaxMemoLen;
 }
 
 impl rtoken_relayers::Trait for Runtime {
@@ -980,7 +986,7 @@ construct_runtime!(
 		RFis: rfis::{Module, Call, Storage, Event<T>, ValidateUnsigned},
 		BridgeRelayers: bridge_relayers::{Module, Call, Storage, Event<T>},
 		BridgeCommon: bridge_common::{Module, Call, Storage, Event<T>},
-		BridgeSwap: bridge_swap::{Module, Call},
+		BridgeSwap: bridge_swap::{Module, Call, Storage, Event<T>},
 		RTokenRelayers: rtoken_relayers::{Module, Call, Storage, Event<T>},
 		RTokenVotes: rtoken_votes::{Module, Call, Storage, Event<T>},
 		RTokenLedger: rtoken_ledger::{Module, Call, Storage, Event<T>},
@@ -1185,6 +1191,32 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl bridge_swap_runtime_api::BridgeSwapApi<Block> for Runtime {
+		fn chain_transfer_fee(dest_id: ChainId) -> Option<Balance> {
+			BridgeSwap::chain_transfer_fee(dest_id)
+		}
+
+		fn is_chain_whitelisted(dest_id: ChainId) -> bool {
+			BridgeSwap::is_chain_whitelisted(dest_id)
+		}
+
+		fn whitelisted_chains() -> Vec<ChainId> {
+			BridgeSwap::whitelisted_chains()
+		}
+
+		fn bridge_account_balance() -> Balance {
+			BridgeSwap::bridge_account_balance()
+		}
+
+		fn total_bridged_out(dest_id: ChainId) -> U256 {
+			BridgeSwap::total_bridged_out(dest_id)
+		}
+
+		fn estimate_transfer_native(who: AccountId, amount: Balance, dest_id: ChainId) -> Result<(Balance, Balance), DispatchError> {
+			BridgeSwap::estimate_transfer_native(&who, amount, dest_id)
+		}
+	}
+
 	impl sp_session::SessionKeys<Block> for Runtime {
 		fn generate_session_keys(seed: Option<Vec<u8>>) -> Vec<u8> {
 			SessionKeys::generate(seed)